@@ -1,3 +1,5 @@
+use crate::{Fusion, Valid, Validator};
+
 pub trait Append<A> {
     type Out;
     fn append(self, a: A) -> Self::Out;
@@ -76,3 +78,27 @@ impl<A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10> Append<A10>
         (a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10)
     }
 }
+
+macro_rules! impl_map_n {
+    ($name:ident, $($a:ident: $t:ident),+) => {
+        impl<$($t,)+ E, T> Fusion<($($t,)+), E, T> {
+            /// Applies `f` to the values of all the fused validations.
+            pub fn $name<B>(self, f: impl FnOnce($($t),+) -> B) -> Valid<B, E, T> {
+                self.map(|($($a,)+)| f($($a),+))
+            }
+        }
+    };
+}
+
+impl_map_n!(map2, a0: A0, a1: A1);
+impl_map_n!(map3, a0: A0, a1: A1, a2: A2);
+impl_map_n!(map4, a0: A0, a1: A1, a2: A2, a3: A3);
+impl_map_n!(map5, a0: A0, a1: A1, a2: A2, a3: A3, a4: A4);
+impl_map_n!(map6, a0: A0, a1: A1, a2: A2, a3: A3, a4: A4, a5: A5);
+impl_map_n!(map7, a0: A0, a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6);
+impl_map_n!(map8, a0: A0, a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7);
+impl_map_n!(map9, a0: A0, a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8);
+impl_map_n!(map10, a0: A0, a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9);
+impl_map_n!(
+    map11, a0: A0, a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6, a7: A7, a8: A8, a9: A9, a10: A10
+);
@@ -0,0 +1,451 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::Cause;
+
+/// A failure to load a [`Catalog`].
+#[derive(Debug, Error)]
+pub enum CatalogError {
+    #[error("failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("{locale}:{line}: {message}")]
+    Parse {
+        locale: String,
+        line: usize,
+        message: String,
+    },
+}
+
+/// Translated messages for causes, looked up by their [`Cause::code`].
+///
+/// Messages are written in a subset of the Fluent syntax: one `code = message`
+/// per line, `#` comments, and indented lines continuing the message above.
+/// Placeholders such as `{ $max }` are replaced with the [`Cause::params`] of
+/// the same name, and `{ "text" }` with the quoted text. Any other Fluent
+/// syntax, such as select expressions, terms or attributes, is rejected with
+/// [`CatalogError::Parse`].
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{Catalog, Cause};
+///
+/// let catalog = Catalog::new()
+///     .messages("en", "string.too_long = must be at most { $max } characters")?
+///     .messages("de", "string.too_long = darf höchstens { $max } Zeichen lang sein")?;
+///
+/// let cause: Cause<&str, ()> = Cause::new("is too long")
+///     .code("string.too_long")
+///     .param("max", 64);
+/// assert_eq!(catalog.render(&cause, "de-CH"), "darf höchstens 64 Zeichen lang sein");
+/// assert_eq!(catalog.render(&cause, "fr"), "must be at most 64 characters");
+/// # Ok::<(), tailcall_valid::CatalogError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Catalog {
+    locales: HashMap<String, HashMap<String, String>>,
+    default_locale: String,
+}
+
+impl Default for Catalog {
+    fn default() -> Self {
+        Catalog {
+            locales: HashMap::new(),
+            default_locale: "en".to_string(),
+        }
+    }
+}
+
+impl Catalog {
+    pub fn new() -> Self {
+        Catalog::default()
+    }
+
+    /// Loads every `<locale>.ftl` file of a directory.
+    pub fn load_dir(path: impl AsRef<Path>) -> Result<Catalog, CatalogError> {
+        let path = path.as_ref();
+        let io = |source| CatalogError::Io {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        let mut catalog = Catalog::new();
+        let mut files: Vec<PathBuf> = std::fs::read_dir(path)
+            .map_err(io)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<_, _>>()
+            .map_err(io)?;
+        files.sort();
+        for file in files {
+            if file.extension().is_none_or(|extension| extension != "ftl") {
+                continue;
+            }
+            let Some(locale) = file.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let source = std::fs::read_to_string(&file).map_err(|source| CatalogError::Io {
+                path: file.clone(),
+                source,
+            })?;
+            catalog = catalog.messages(locale, &source)?;
+        }
+        Ok(catalog)
+    }
+
+    /// Adds the messages of a locale, replacing existing messages with the
+    /// same code.
+    pub fn messages(mut self, locale: &str, source: &str) -> Result<Catalog, CatalogError> {
+        let messages = parse(source).map_err(|(line, message)| CatalogError::Parse {
+            locale: locale.to_string(),
+            line,
+            message,
+        })?;
+        self.locales
+            .entry(locale.to_string())
+            .or_default()
+            .extend(messages);
+        Ok(self)
+    }
+
+    /// Sets the locale used when no message is found for the requested one.
+    /// Defaults to `en`.
+    pub fn default_locale(mut self, locale: impl Into<String>) -> Catalog {
+        self.default_locale = locale.into();
+        self
+    }
+
+    /// Returns the template for a code, trying `locale`, then its parents
+    /// (`de` for `de-CH`) and finally the default locale.
+    pub fn message(&self, locale: &str, code: &str) -> Option<&str> {
+        let mut candidates = vec![locale];
+        let mut rest = locale;
+        while let Some((parent, _)) = rest.rsplit_once('-') {
+            candidates.push(parent);
+            rest = parent;
+        }
+        candidates.push(&self.default_locale);
+
+        candidates.into_iter().find_map(|locale| {
+            self.locales
+                .get(locale)
+                .and_then(|messages| messages.get(code))
+                .map(String::as_str)
+        })
+    }
+
+    /// Renders the message of a cause in the given locale, falling back to
+    /// the `Display` of its error when the cause has no code or the code has
+    /// no message.
+    pub fn render<E: Display, T>(&self, cause: &Cause<E, T>, locale: &str) -> String {
+        cause
+            .code
+            .as_deref()
+            .and_then(|code| self.message(locale, code))
+            .map(|template| format(template, &cause.params))
+            .unwrap_or_else(|| cause.error.to_string())
+    }
+}
+
+/// Parses `code = message` lines into a map, or returns the line number and
+/// description of the first error.
+fn parse(source: &str) -> Result<HashMap<String, String>, (usize, String)> {
+    let mut messages = HashMap::new();
+    let mut current: Option<(usize, String, String)> = None;
+    for (i, line) in source.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            continue;
+        }
+        if line.starts_with([' ', '\t']) && !trimmed.is_empty() {
+            match &mut current {
+                Some(_) if trimmed.starts_with('.') => {
+                    return Err((i + 1, "attributes are not supported".to_string()))
+                }
+                Some((_, _, message)) => {
+                    if !message.is_empty() {
+                        message.push('\n');
+                    }
+                    message.push_str(trimmed);
+                    continue;
+                }
+                None => return Err((i + 1, "unexpected indented line".to_string())),
+            }
+        }
+
+        if let Some((line, code, message)) = current.take() {
+            check_placeables(&message).map_err(|error| (line, error))?;
+            messages.insert(code, message);
+        }
+        if trimmed.is_empty() {
+            continue;
+        }
+        let Some((code, message)) = line.split_once('=') else {
+            return Err((i + 1, "expected `code = message`".to_string()));
+        };
+        let code = code.trim();
+        if code.starts_with('-') {
+            return Err((i + 1, "terms are not supported".to_string()));
+        }
+        let valid = code
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+        if code.is_empty() || !valid {
+            return Err((i + 1, format!("invalid code `{}`", code)));
+        }
+        current = Some((i + 1, code.to_string(), message.trim().to_string()));
+    }
+    if let Some((line, code, message)) = current {
+        check_placeables(&message).map_err(|error| (line, error))?;
+        messages.insert(code, message);
+    }
+    Ok(messages)
+}
+
+/// Checks that every placeable of a message is a `{ $name }` variable or a
+/// `{ "text" }` literal, the only ones [`format`] knows how to fill in.
+fn check_placeables(message: &str) -> Result<(), String> {
+    let mut rest = message;
+    while let Some(start) = rest.find(['{', '}']) {
+        if rest[start..].starts_with('}') {
+            return Err("unexpected `}`".to_string());
+        }
+        let Some(end) = closing_brace(&rest[start + 1..]).map(|end| start + 1 + end) else {
+            return Err("unclosed placeable".to_string());
+        };
+        let placeable = rest[start + 1..end].trim();
+        let variable = placeable.strip_prefix('$').is_some_and(|name| {
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+        });
+        if !variable && unescape(placeable).is_none() {
+            return Err(format!(
+                "unsupported placeable `{{ {} }}`, expected `{{ $name }}` or a string literal",
+                placeable
+            ));
+        }
+        rest = &rest[end + 1..];
+    }
+    Ok(())
+}
+
+/// Returns the position of the `}` that closes a placeable, given the text
+/// right after its `{`. Braces inside string literals such as `"}"` are
+/// skipped.
+fn closing_brace(placeable: &str) -> Option<usize> {
+    let mut literal = false;
+    let mut chars = placeable.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' if literal => {
+                chars.next();
+            }
+            '"' => literal = !literal,
+            '}' if !literal => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns the text of a string literal placeable such as `"\"quoted\""`,
+/// resolving the `\"`, `\\`, `\uHHHH` and `\UHHHHHH` escapes. Returns `None`
+/// if the placeable is not exactly one well formed literal.
+fn unescape(placeable: &str) -> Option<String> {
+    let literal = placeable.strip_prefix('"')?.strip_suffix('"')?;
+    let mut text = String::with_capacity(literal.len());
+    let mut chars = literal.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return None,
+            '\\' => {
+                let digits = match chars.next()? {
+                    c @ ('"' | '\\') => {
+                        text.push(c);
+                        continue;
+                    }
+                    'u' => 4,
+                    'U' => 6,
+                    _ => return None,
+                };
+                let hex: String = chars.by_ref().take(digits).collect();
+                if hex.len() != digits {
+                    return None;
+                }
+                text.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+            }
+            c => text.push(c),
+        }
+    }
+    Some(text)
+}
+
+/// Replaces the `{ $name }` placeholders of a template. Placeholders without
+/// a value are kept as they are.
+fn format(template: &str, params: &std::collections::BTreeMap<String, String>) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        let Some(end) = closing_brace(&rest[start + 1..]).map(|end| start + 1 + end) else {
+            rest = &rest[start..];
+            break;
+        };
+        let placeholder = rest[start + 1..end].trim();
+        let value = match placeholder.strip_prefix('$') {
+            Some(name) => params.get(name).cloned(),
+            None => unescape(placeholder),
+        };
+        match value {
+            Some(value) => output.push_str(&value),
+            None => output.push_str(&rest[start..=end]),
+        }
+        rest = &rest[end + 1..];
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{Catalog, CatalogError};
+    use crate::Cause;
+
+    fn catalog() -> Catalog {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/catalog");
+        Catalog::load_dir(dir).unwrap()
+    }
+
+    fn too_long() -> Cause<&'static str, ()> {
+        Cause::new("is too long")
+            .code("string.too_long")
+            .param("max", 64)
+            .param("actual", 80)
+    }
+
+    #[test]
+    fn test_load_dir() {
+        let catalog = catalog();
+        assert_eq!(
+            catalog.render(&too_long(), "en"),
+            "must be at most 64 characters, got 80"
+        );
+        assert_eq!(
+            catalog.render(&too_long(), "de"),
+            "darf höchstens 64 Zeichen lang sein, ist aber 80"
+        );
+    }
+
+    #[test]
+    fn test_multiline_message() {
+        let cause = Cause::<&str, ()>::new("required").code("field.required");
+        assert_eq!(
+            catalog().render(&cause, "en"),
+            "this field is required\nadd it to the configuration"
+        );
+    }
+
+    #[test]
+    fn test_locale_fallback() {
+        let catalog = catalog();
+        assert_eq!(
+            catalog.render(&too_long(), "de-AT"),
+            "darf höchstens 64 Zeichen lang sein, ist aber 80"
+        );
+        assert_eq!(
+            catalog.render(&too_long(), "fr"),
+            "must be at most 64 characters, got 80"
+        );
+
+        let cause = Cause::<&str, ()>::new("required").code("field.required");
+        assert_eq!(
+            catalog.clone().default_locale("de").render(&cause, "fr"),
+            "Pflichtfeld"
+        );
+    }
+
+    #[test]
+    fn test_display_fallback() {
+        let catalog = catalog();
+        assert_eq!(catalog.render(&Cause::<_, ()>::new("plain"), "en"), "plain");
+
+        let unknown = Cause::<_, ()>::new("unknown").code("unknown.code");
+        assert_eq!(catalog.render(&unknown, "en"), "unknown");
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let catalog = Catalog::new()
+            .messages("en", r#"code = { $a } and {$b}, { $missing } { "{" }"#)
+            .unwrap();
+        let cause = Cause::<&str, ()>::new("")
+            .code("code")
+            .param("a", 1)
+            .param("b", "two");
+        assert_eq!(catalog.render(&cause, "en"), "1 and two, { $missing } {");
+    }
+
+    #[test]
+    fn test_literal_braces() {
+        let catalog = Catalog::new()
+            .messages(
+                "en",
+                r#"open = a { "{" } b
+close = a { "}" } b
+escaped = { "\"}\\" } { "\u00e9" }"#,
+            )
+            .unwrap();
+        let render = |code: &str| catalog.render(&Cause::<&str, ()>::new("").code(code), "en");
+        assert_eq!(render("open"), "a { b");
+        assert_eq!(render("close"), "a } b");
+        assert_eq!(render("escaped"), r#""}\ é"#);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Catalog::new()
+            .messages("en", "# comment\nok = fine\nnot a message\n")
+            .unwrap_err();
+        assert!(matches!(error, CatalogError::Parse { line: 3, .. }));
+        assert_eq!(error.to_string(), "en:3: expected `code = message`");
+    }
+
+    #[test]
+    fn test_unsupported_syntax() {
+        let error = |source: &str| {
+            Catalog::new()
+                .messages("en", source)
+                .unwrap_err()
+                .to_string()
+        };
+        let select = "ok = fine\nitems =\n    { $n ->\n        [one] one item\n       *[other] { $n } items\n    }\n";
+        assert_eq!(
+            error(select),
+            "en:2: unsupported placeable `{ $n ->\n[one] one item\n*[other] { $n }`, expected `{ $name }` or a string literal"
+        );
+        assert_eq!(error("-brand = Tailcall"), "en:1: terms are not supported");
+        assert_eq!(
+            error("welcome = { -brand }"),
+            "en:1: unsupported placeable `{ -brand }`, expected `{ $name }` or a string literal"
+        );
+        assert_eq!(
+            error("login =\n    .title = Log in"),
+            "en:2: attributes are not supported"
+        );
+        assert_eq!(error("a = { $max"), "en:1: unclosed placeable");
+        assert_eq!(error(r#"a = { "}" "#), "en:1: unclosed placeable");
+        assert_eq!(
+            error(r#"a = { "\q" }"#),
+            r#"en:1: unsupported placeable `{ "\q" }`, expected `{ $name }` or a string literal"#
+        );
+        assert_eq!(error("a = max }"), "en:1: unexpected `}`");
+    }
+}
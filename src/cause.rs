@@ -1,13 +1,75 @@
-use std::{collections::VecDeque, fmt::Display};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Display;
 
 use derive_setters::Setters;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-#[derive(Clone, PartialEq, Debug, Setters, Error)]
+use crate::Span;
+
+/// How serious a [`Cause`] is.
+///
+/// Warnings and notes are carried along as non-fatal diagnostics: a
+/// validation with a value fails only if it has errors, and `to_result`
+/// reports only the errors. A validation without a value is failed even when
+/// it holds no errors at all.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+    Info,
+}
+
+impl Severity {
+    pub fn is_error(&self) -> bool {
+        matches!(self, Severity::Error)
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Info => write!(f, "info"),
+        }
+    }
+}
+
+/// A single validation error along with the trace of where it occurred.
+///
+/// Serializes as `{"message": error, "path": [trace, ...]}`, with an
+/// additional `severity` member for anything other than errors.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Setters, Error, Serialize, Deserialize)]
 pub struct Cause<E, T> {
+    #[serde(rename = "message")]
     pub error: E,
     #[setters(skip)]
+    #[serde(rename = "path", default = "VecDeque::new")]
     pub trace: VecDeque<T>,
+    #[serde(default, skip_serializing_if = "Severity::is_error")]
+    pub severity: Severity,
+    /// Where in the source the cause was found.
+    #[setters(strip_option)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+    /// A suggestion on how to fix the cause.
+    #[setters(strip_option, into)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+    /// A stable identifier of the kind of error, eg. `string.too_long`, used
+    /// to look up translated messages in a [`crate::Catalog`].
+    #[setters(strip_option, into)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// Named values the message of the error refers to.
+    #[setters(skip)]
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
 }
 
 impl<E: Display, T: Display> Display for Cause<E, T> {
@@ -29,6 +91,11 @@ impl<E, T> Cause<E, T> {
         Cause {
             error: e,
             trace: Default::default(),
+            severity: Severity::Error,
+            span: None,
+            help: None,
+            code: None,
+            params: BTreeMap::new(),
         }
     }
 
@@ -37,11 +104,79 @@ impl<E, T> Cause<E, T> {
         self
     }
 
+    /// Sets a named value that the message of the error refers to.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::Cause;
+    /// let cause: Cause<&str, ()> = Cause::new("is too long")
+    ///     .code("string.too_long")
+    ///     .param("max", 64)
+    ///     .param("actual", 80);
+    /// assert_eq!(cause.params["max"], "64");
+    /// ```
+    pub fn param(mut self, name: impl Into<String>, value: impl Display) -> Self {
+        self.params.insert(name.into(), value.to_string());
+        self
+    }
+
     pub fn transform<E1>(self, e: impl Fn(E) -> E1) -> Cause<E1, T> {
         Cause {
             error: e(self.error),
             trace: self.trace,
+            severity: self.severity,
+            span: self.span,
+            help: self.help,
+            code: self.code,
+            params: self.params,
+        }
+    }
+}
+
+/// Recorded in place of the causes that were never collected because
+/// validation stopped early, eg. in [`crate::Valid::from_iter_limit`].
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::Truncated;
+/// let truncated = Truncated { limit: 1, dropped: 2, unvalidated: true };
+/// assert_eq!(
+///     truncated.to_string(),
+///     "stopped after 1 error, 2 more errors were dropped and the remaining items were not validated"
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Truncated {
+    /// The maximum number of errors that were kept.
+    pub limit: usize,
+    /// The number of errors that were found past the limit and dropped.
+    pub dropped: usize,
+    /// Whether items were left without being validated.
+    pub unvalidated: bool,
+}
+
+impl Display for Truncated {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let errors = |n: usize| if n == 1 { "error" } else { "errors" };
+        write!(f, "stopped after {} {}", self.limit, errors(self.limit))?;
+        if self.dropped > 0 {
+            let verb = if self.dropped == 1 { "was" } else { "were" };
+            let (n, e) = (self.dropped, errors(self.dropped));
+            write!(f, ", {} more {} {} dropped", n, e, verb)?;
+        }
+        if self.unvalidated {
+            let sep = if self.dropped > 0 { " and" } else { "," };
+            write!(f, "{} the remaining items were not validated", sep)?;
         }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Truncated {}
+
+impl From<Truncated> for String {
+    fn from(value: Truncated) -> Self {
+        value.to_string()
     }
 }
 
@@ -55,4 +190,54 @@ mod tests {
         let cause = Cause::new("error").trace("trace0").trace("trace1");
         assert_eq!(cause.to_string(), "[trace1, trace0] error");
     }
+
+    #[test]
+    fn test_serde() {
+        use super::Cause;
+        use crate::PathSegment;
+
+        let cause: Cause<String, PathSegment> = Cause::new("error".to_string())
+            .trace(PathSegment::Index(0))
+            .trace("users".into());
+        let json = serde_json::to_value(&cause).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "message": "error", "path": ["users", 0] })
+        );
+        assert_eq!(serde_json::from_value::<Cause<_, _>>(json).unwrap(), cause);
+    }
+
+    #[test]
+    fn test_serde_severity() {
+        use super::{Cause, Severity};
+
+        let cause: Cause<String, String> =
+            Cause::new("deprecated".to_string()).severity(Severity::Warning);
+        let json = serde_json::to_value(&cause).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "message": "deprecated", "path": [], "severity": "warning" })
+        );
+        assert_eq!(serde_json::from_value::<Cause<_, _>>(json).unwrap(), cause);
+    }
+
+    #[test]
+    fn test_serde_code() {
+        use super::Cause;
+
+        let cause: Cause<String, String> = Cause::new("is too long".to_string())
+            .code("string.too_long")
+            .param("max", 64);
+        let json = serde_json::to_value(&cause).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "message": "is too long",
+                "path": [],
+                "code": "string.too_long",
+                "params": { "max": "64" }
+            })
+        );
+        assert_eq!(serde_json::from_value::<Cause<_, _>>(json).unwrap(), cause);
+    }
 }
@@ -0,0 +1,296 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::hash::{BuildHasher, Hash};
+
+use crate::{PathSegment, Valid, ValidFrom, Validator};
+
+/// Converts every element, tracing errors with the index of the element.
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{PathSegment, Valid, ValidFrom, ValidInto, Validator};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Port(u16);
+///
+/// impl ValidFrom<i64> for Port {
+///     type Error = String;
+///     type Trace = PathSegment;
+///
+///     fn valid_from(port: i64) -> Valid<Self, String, PathSegment> {
+///         match u16::try_from(port) {
+///             Ok(port) => Valid::succeed(Port(port)),
+///             Err(_) => Valid::fail(format!("{} is not a valid port", port)),
+///         }
+///     }
+/// }
+///
+/// let ports: Valid<Vec<Port>, _, _> = vec![80, -1, 443, 70000].valid_into();
+/// let causes = ports.to_result().unwrap_err();
+/// assert_eq!(causes[0].to_string(), "[1] -1 is not a valid port");
+/// assert_eq!(causes[1].to_string(), "[3] 70000 is not a valid port");
+/// ```
+impl<S, T> ValidFrom<Vec<S>> for Vec<T>
+where
+    T: ValidFrom<S>,
+    T::Trace: From<PathSegment>,
+{
+    type Error = T::Error;
+    type Trace = T::Trace;
+
+    fn valid_from(source: Vec<S>) -> Valid<Self, Self::Error, Self::Trace> {
+        Valid::from_iter_indexed(source, T::valid_from)
+    }
+}
+
+/// Converts the value if there is one.
+impl<S, T: ValidFrom<S>> ValidFrom<Option<S>> for Option<T> {
+    type Error = T::Error;
+    type Trace = T::Trace;
+
+    fn valid_from(source: Option<S>) -> Valid<Self, Self::Error, Self::Trace> {
+        match source {
+            Some(s) => T::valid_from(s).map(Some),
+            None => Valid::succeed(None),
+        }
+    }
+}
+
+impl<S, T: ValidFrom<S>> ValidFrom<Box<S>> for Box<T> {
+    type Error = T::Error;
+    type Trace = T::Trace;
+
+    fn valid_from(source: Box<S>) -> Valid<Self, Self::Error, Self::Trace> {
+        T::valid_from(*source).map(Box::new)
+    }
+}
+
+/// Converts every value, tracing errors with the key of the entry.
+impl<K, S, T> ValidFrom<BTreeMap<K, S>> for BTreeMap<K, T>
+where
+    K: Ord + Display,
+    T: ValidFrom<S>,
+    T::Trace: From<PathSegment>,
+{
+    type Error = T::Error;
+    type Trace = T::Trace;
+
+    fn valid_from(source: BTreeMap<K, S>) -> Valid<Self, Self::Error, Self::Trace> {
+        Valid::from_map(source, |_, s| T::valid_from(s))
+    }
+}
+
+/// Converts every value, tracing errors with the key of the entry. Causes are
+/// reported in the iteration order of the map.
+impl<K, S, T, H> ValidFrom<HashMap<K, S, H>> for HashMap<K, T, H>
+where
+    K: Eq + Hash + Display,
+    H: BuildHasher + Default,
+    T: ValidFrom<S>,
+    T::Trace: From<PathSegment>,
+{
+    type Error = T::Error;
+    type Trace = T::Trace;
+
+    fn valid_from(source: HashMap<K, S, H>) -> Valid<Self, Self::Error, Self::Trace> {
+        Valid::from_map(source, |_, s| T::valid_from(s))
+    }
+}
+
+/// Converts every element, tracing errors with the index of the element.
+impl<S, T, const N: usize> ValidFrom<[S; N]> for [T; N]
+where
+    T: ValidFrom<S>,
+    T::Trace: From<PathSegment>,
+{
+    type Error = T::Error;
+    type Trace = T::Trace;
+
+    fn valid_from(source: [S; N]) -> Valid<Self, Self::Error, Self::Trace> {
+        Valid::from_iter_indexed(source, T::valid_from).map(|values| match values.try_into() {
+            Ok(array) => array,
+            Err(_) => unreachable!("every element was converted"),
+        })
+    }
+}
+
+/// Converts every element of a tuple, tracing errors with the position of the
+/// element. All the elements must share the error and trace types.
+macro_rules! impl_valid_from_tuple {
+    ($($i:tt: $s:ident => $t:ident),+) => {
+        impl<E, R, $($s, $t),+> ValidFrom<($($s,)+)> for ($($t,)+)
+        where
+            R: From<PathSegment>,
+            $($t: ValidFrom<$s, Error = E, Trace = R>,)+
+        {
+            type Error = E;
+            type Trace = R;
+
+            // Every value is bound to the name of its type parameter, which
+            // is free in the value namespace.
+            #[allow(non_snake_case)]
+            fn valid_from(source: ($($s,)+)) -> Valid<Self, E, R> {
+                let mut causes = Vec::new();
+                $(
+                    let Valid($t, more) = $t::valid_from(source.$i).trace(PathSegment::Index($i));
+                    causes.extend(more);
+                )+
+                let value = match ($($t,)+) {
+                    ($(Some($t),)+) => Some(($($t,)+)),
+                    _ => None,
+                };
+                Valid(value, causes)
+            }
+        }
+    };
+}
+
+impl_valid_from_tuple!(0: S0 => T0);
+impl_valid_from_tuple!(0: S0 => T0, 1: S1 => T1);
+impl_valid_from_tuple!(0: S0 => T0, 1: S1 => T1, 2: S2 => T2);
+impl_valid_from_tuple!(0: S0 => T0, 1: S1 => T1, 2: S2 => T2, 3: S3 => T3);
+impl_valid_from_tuple!(0: S0 => T0, 1: S1 => T1, 2: S2 => T2, 3: S3 => T3, 4: S4 => T4);
+impl_valid_from_tuple!(
+    0: S0 => T0, 1: S1 => T1, 2: S2 => T2, 3: S3 => T3, 4: S4 => T4, 5: S5 => T5
+);
+impl_valid_from_tuple!(
+    0: S0 => T0, 1: S1 => T1, 2: S2 => T2, 3: S3 => T3, 4: S4 => T4, 5: S5 => T5, 6: S6 => T6
+);
+impl_valid_from_tuple!(
+    0: S0 => T0, 1: S1 => T1, 2: S2 => T2, 3: S3 => T3, 4: S4 => T4, 5: S5 => T5, 6: S6 => T6,
+    7: S7 => T7
+);
+impl_valid_from_tuple!(
+    0: S0 => T0, 1: S1 => T1, 2: S2 => T2, 3: S3 => T3, 4: S4 => T4, 5: S5 => T5, 6: S6 => T6,
+    7: S7 => T7, 8: S8 => T8
+);
+impl_valid_from_tuple!(
+    0: S0 => T0, 1: S1 => T1, 2: S2 => T2, 3: S3 => T3, 4: S4 => T4, 5: S5 => T5, 6: S6 => T6,
+    7: S7 => T7, 8: S8 => T8, 9: S9 => T9
+);
+impl_valid_from_tuple!(
+    0: S0 => T0, 1: S1 => T1, 2: S2 => T2, 3: S3 => T3, 4: S4 => T4, 5: S5 => T5, 6: S6 => T6,
+    7: S7 => T7, 8: S8 => T8, 9: S9 => T9, 10: S10 => T10
+);
+impl_valid_from_tuple!(
+    0: S0 => T0, 1: S1 => T1, 2: S2 => T2, 3: S3 => T3, 4: S4 => T4, 5: S5 => T5, 6: S6 => T6,
+    7: S7 => T7, 8: S8 => T8, 9: S9 => T9, 10: S10 => T10, 11: S11 => T11
+);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{Cause, PathSegment, Severity, Valid, ValidFrom, ValidInto, Validator};
+
+    #[derive(Debug, PartialEq)]
+    struct Port(u16);
+
+    impl ValidFrom<i64> for Port {
+        type Error = String;
+        type Trace = PathSegment;
+
+        fn valid_from(port: i64) -> Valid<Self, String, PathSegment> {
+            match u16::try_from(port) {
+                Ok(0) => Valid::succeed(Port(0)).warn("port 0 is picked by the system".into()),
+                Ok(port) => Valid::succeed(Port(port)),
+                Err(_) => Valid::fail(format!("{} is not a valid port", port)),
+            }
+        }
+    }
+
+    fn errors<A>(valid: Valid<A, String, PathSegment>) -> Vec<String> {
+        match valid.to_result() {
+            Ok(_) => Vec::new(),
+            Err(causes) => causes.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn test_vec() {
+        let ports: Valid<Vec<Port>, _, _> = vec![80, 443].valid_into();
+        assert_eq!(ports, Valid::succeed(vec![Port(80), Port(443)]));
+
+        let ports: Valid<Vec<Port>, _, _> = vec![-1, 80, 70000].valid_into();
+        assert_eq!(
+            errors(ports),
+            vec![
+                "[0] -1 is not a valid port",
+                "[2] 70000 is not a valid port"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_keeps_warnings() {
+        let ports: Valid<Vec<Port>, _, _> = vec![80, 0].valid_into();
+        assert_eq!(
+            ports.causes(),
+            &[Cause::new("port 0 is picked by the system".to_string())
+                .severity(Severity::Warning)
+                .trace(PathSegment::Index(1))]
+        );
+        assert_eq!(ports.to_result(), Ok(vec![Port(80), Port(0)]));
+    }
+
+    #[test]
+    fn test_option_and_box() {
+        assert_eq!(Option::<Port>::valid_from(None), Valid::succeed(None));
+        assert_eq!(
+            Option::<Port>::valid_from(Some(80)),
+            Valid::succeed(Some(Port(80)))
+        );
+        assert_eq!(
+            errors(Option::<Port>::valid_from(Some(-1))),
+            vec!["[] -1 is not a valid port"]
+        );
+        assert_eq!(
+            Box::<Port>::valid_from(Box::new(80)),
+            Valid::succeed(Box::new(Port(80)))
+        );
+    }
+
+    #[test]
+    fn test_maps() {
+        let source = BTreeMap::from([("http", 80), ("bad", -1), ("https", 443)]);
+        let ports: Valid<BTreeMap<_, Port>, _, _> = source.valid_into();
+        assert_eq!(errors(ports), vec!["[bad] -1 is not a valid port"]);
+
+        let source = HashMap::from([("http", 80), ("https", 443)]);
+        let ports = HashMap::<_, Port>::valid_from(source).to_result().unwrap();
+        assert_eq!(ports["https"], Port(443));
+
+        let ports = HashMap::<_, Port>::valid_from(HashMap::from([("bad", -1)]));
+        let causes = ports.to_result().unwrap_err();
+        assert_eq!(causes[0].trace[0], PathSegment::key("bad"));
+    }
+
+    #[test]
+    fn test_array() {
+        let ports: Valid<[Port; 2], _, _> = [80, 443].valid_into();
+        assert_eq!(ports, Valid::succeed([Port(80), Port(443)]));
+
+        let ports = <[Port; 3]>::valid_from([80, -1, -2]);
+        assert_eq!(
+            errors(ports),
+            vec!["[1] -1 is not a valid port", "[2] -2 is not a valid port"]
+        );
+    }
+
+    #[test]
+    fn test_tuple() {
+        let ports: Valid<(Port, Vec<Port>), _, _> = (80, vec![443, -1]).valid_into();
+        assert_eq!(errors(ports), vec!["[1, 1] -1 is not a valid port"]);
+
+        let ports = <(Port, Option<Port>, Port)>::valid_from((-1, None, 70000));
+        assert_eq!(
+            errors(ports),
+            vec![
+                "[0] -1 is not a valid port",
+                "[2] 70000 is not a valid port"
+            ]
+        );
+    }
+}
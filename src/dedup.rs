@@ -0,0 +1,352 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+use serde::Serialize;
+
+use crate::{Cause, Severity, Span, Valid};
+
+/// How [`Valid::dedup_causes_by`] decides that two causes are the same.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Dedup {
+    /// Equal causes: same error, trace, severity, span, help, code and params.
+    #[default]
+    Exact,
+    /// Same error and severity, wherever it occurred.
+    Error,
+    /// Same error, trace and severity. The details of the repeated causes
+    /// are merged into the first one, see [`MergedCause::merge`].
+    Merge,
+}
+
+/// Numbers every cause by the group `key` puts it in, with groups numbered
+/// in the order of their first cause.
+fn groups<'a, E, T, K: Eq + Hash>(
+    causes: &'a [Cause<E, T>],
+    key: impl Fn(&'a Cause<E, T>) -> K,
+) -> Vec<usize> {
+    let mut index = HashMap::with_capacity(causes.len());
+    causes
+        .iter()
+        .map(|cause| {
+            let next = index.len();
+            *index.entry(key(cause)).or_insert(next)
+        })
+        .collect()
+}
+
+/// Fills the span, help and code that `first` is missing from `other`, and
+/// adds the params of `other` that `first` does not have.
+fn merge_details<E, T>(first: &mut Cause<E, T>, other: Cause<E, T>) {
+    first.span = first.span.take().or(other.span);
+    first.help = first.help.take().or(other.help);
+    first.code = first.code.take().or(other.code);
+    for (name, value) in other.params {
+        first.params.entry(name).or_insert(value);
+    }
+}
+
+/// Keeps the first of every group of causes that `strategy` considers the
+/// same.
+fn dedup<E: Eq + Hash, T: Eq + Hash>(
+    causes: Vec<Cause<E, T>>,
+    strategy: Dedup,
+) -> Vec<Cause<E, T>> {
+    let groups = match strategy {
+        Dedup::Exact => groups(&causes, |cause| cause),
+        Dedup::Error => groups(&causes, |cause| (&cause.error, cause.severity)),
+        Dedup::Merge => groups(&causes, |cause| {
+            (&cause.error, &cause.trace, cause.severity)
+        }),
+    };
+    let mut kept: Vec<Cause<E, T>> = Vec::new();
+    for (cause, group) in causes.into_iter().zip(groups) {
+        match kept.get_mut(group) {
+            Some(first) if strategy == Dedup::Merge => merge_details(first, cause),
+            Some(_) => {}
+            None => kept.push(cause),
+        }
+    }
+    kept
+}
+
+impl<A, E: Eq + Hash, T: Eq + Hash> Valid<A, E, T> {
+    /// Removes causes that are equal to an earlier one.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    /// let valid = Valid::<(), &str, &str>::fail("unknown type `Usr`")
+    ///     .trace("user")
+    ///     .zip(Valid::<(), _, _>::fail("unknown type `Usr`").trace("user"))
+    ///     .dedup_causes();
+    /// assert_eq!(valid.to_result().unwrap_err().len(), 1);
+    /// ```
+    pub fn dedup_causes(self) -> Valid<A, E, T> {
+        self.dedup_causes_by(Dedup::Exact)
+    }
+
+    /// Removes causes that are the same as an earlier one according to
+    /// `strategy`. The first occurrence is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Dedup, Valid, Validator};
+    /// let valid = Valid::<(), &str, &str>::fail("unknown type `Usr`")
+    ///     .trace("post")
+    ///     .zip(Valid::<(), _, _>::fail("unknown type `Usr`").trace("comment"))
+    ///     .dedup_causes_by(Dedup::Error);
+    /// let causes = valid.to_result().unwrap_err();
+    /// assert_eq!(causes.len(), 1);
+    /// assert_eq!(causes[0].to_string(), "[post] unknown type `Usr`");
+    /// ```
+    pub fn dedup_causes_by(self, strategy: Dedup) -> Valid<A, E, T> {
+        let Valid(value, causes) = self;
+        Valid(value, dedup(causes, strategy))
+    }
+}
+
+impl<A, E, T: Ord> Valid<A, E, T> {
+    /// Sorts the causes by their trace, so that they are reported in the order
+    /// of the validated input rather than the order they were found in.
+    /// Causes with the same trace keep their order.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    /// let valid = Valid::<(), &str, &str>::fail("is required")
+    ///     .trace("port")
+    ///     .zip(Valid::<(), _, _>::fail("is required").trace("host"))
+    ///     .sort_causes_by_trace();
+    /// let causes = valid.to_result().unwrap_err();
+    /// assert_eq!(causes[0].to_string(), "[host] is required");
+    /// ```
+    pub fn sort_causes_by_trace(self) -> Valid<A, E, T> {
+        let Valid(value, mut causes) = self;
+        causes.sort_by(|a, b| a.trace.cmp(&b.trace));
+        Valid(value, causes)
+    }
+}
+
+/// An error along with every location it occurred at.
+///
+/// Serializes as `{"message": error, "paths": [[trace, ...], ...]}`, with the
+/// `severity`, `span`, `help`, `code` and `params` members of [`Cause`] when
+/// they are set.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct MergedCause<E, T> {
+    #[serde(rename = "message")]
+    pub error: E,
+    #[serde(rename = "paths")]
+    pub traces: Vec<VecDeque<T>>,
+    #[serde(skip_serializing_if = "Severity::is_error")]
+    pub severity: Severity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+}
+
+impl<E: Eq + Hash, T> MergedCause<E, T> {
+    /// Merges causes with the same error and severity, collecting their
+    /// traces. Causes are kept in the order of their first occurrence.
+    ///
+    /// The span, help and code come from the first cause that has them, and
+    /// the params of all the causes are combined, the earliest value of a
+    /// param winning.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{MergedCause, Valid, Validator};
+    /// let causes = Valid::<(), &str, &str>::fail("unknown type `Usr`")
+    ///     .trace("post")
+    ///     .zip(Valid::<(), _, _>::fail("unknown type `Usr`").trace("comment"))
+    ///     .to_result()
+    ///     .unwrap_err();
+    /// let merged = MergedCause::merge(causes);
+    /// assert_eq!(merged.len(), 1);
+    /// assert_eq!(merged[0].to_string(), "unknown type `Usr` (at [post], [comment])");
+    /// ```
+    pub fn merge(causes: impl IntoIterator<Item = Cause<E, T>>) -> Vec<MergedCause<E, T>> {
+        let causes: Vec<Cause<E, T>> = causes.into_iter().collect();
+        let groups = groups(&causes, |cause| (&cause.error, cause.severity));
+        let mut merged: Vec<(Cause<E, T>, Vec<_>)> = Vec::new();
+        for (mut cause, group) in causes.into_iter().zip(groups) {
+            let trace = std::mem::take(&mut cause.trace);
+            match merged.get_mut(group) {
+                Some((first, traces)) => {
+                    traces.push(trace);
+                    merge_details(first, cause);
+                }
+                None => merged.push((cause, vec![trace])),
+            }
+        }
+        merged
+            .into_iter()
+            .map(|(cause, traces)| MergedCause {
+                error: cause.error,
+                traces,
+                severity: cause.severity,
+                span: cause.span,
+                help: cause.help,
+                code: cause.code,
+                params: cause.params,
+            })
+            .collect()
+    }
+}
+
+impl<E: Display, T: Display> Display for MergedCause<E, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (at ", self.error)?;
+        for (i, trace) in self.traces.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "[")?;
+            for (j, entry) in trace.iter().enumerate() {
+                if j > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", entry)?;
+            }
+            write!(f, "]")?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{Dedup, MergedCause};
+    use crate::{Cause, Severity, Valid, Validator};
+
+    fn causes() -> Vec<Cause<&'static str, &'static str>> {
+        vec![
+            Cause::new("b").trace("y"),
+            Cause::new("a").trace("x"),
+            Cause::new("b").trace("y"),
+            Cause::new("a").trace("z"),
+            Cause::new("a").trace("x").severity(Severity::Warning),
+        ]
+    }
+
+    #[test]
+    fn test_dedup_exact() {
+        let valid = Valid::<(), _, _>::from(causes()).dedup_causes();
+        let expected = vec![
+            Cause::new("b").trace("y"),
+            Cause::new("a").trace("x"),
+            Cause::new("a").trace("z"),
+            Cause::new("a").trace("x").severity(Severity::Warning),
+        ];
+        assert_eq!(valid.causes(), expected);
+    }
+
+    #[test]
+    fn test_dedup_exact_compares_details() {
+        let valid = Valid::<(), _, _>::from(vec![
+            Cause::new("too long")
+                .trace("name")
+                .help("use at most 8 characters"),
+            Cause::new("too long").trace("name").code("string.too_long"),
+            Cause::new("too long").trace("name").code("string.too_long"),
+        ])
+        .dedup_causes();
+        assert_eq!(valid.causes().len(), 2);
+    }
+
+    #[test]
+    fn test_dedup_error() {
+        let valid = Valid::<(), _, _>::from(causes()).dedup_causes_by(Dedup::Error);
+        let expected = vec![
+            Cause::new("b").trace("y"),
+            Cause::new("a").trace("x"),
+            Cause::new("a").trace("x").severity(Severity::Warning),
+        ];
+        assert_eq!(valid.causes(), expected);
+    }
+
+    #[test]
+    fn test_dedup_merge() {
+        let valid = Valid::<(), _, _>::from(vec![
+            Cause::new("too long").trace("name").param("max", "8"),
+            Cause::new("too long").trace("bio"),
+            Cause::new("too long")
+                .trace("name")
+                .code("string.too_long")
+                .param("max", "16")
+                .param("length", "12"),
+        ])
+        .dedup_causes_by(Dedup::Merge);
+        let expected = vec![
+            Cause::new("too long")
+                .trace("name")
+                .code("string.too_long")
+                .param("max", "8")
+                .param("length", "12"),
+            Cause::new("too long").trace("bio"),
+        ];
+        assert_eq!(valid.causes(), expected);
+    }
+
+    #[test]
+    fn test_dedup_warnings() {
+        let valid = Valid::<(), &str, ()>::succeed(())
+            .warn("deprecated")
+            .zip(Valid::succeed(()).warn("deprecated"))
+            .dedup_causes();
+        assert_eq!(valid.warnings().len(), 1);
+    }
+
+    #[test]
+    fn test_sort_by_trace() {
+        let valid = Valid::<(), _, _>::from(causes()).sort_causes_by_trace();
+        let result = valid.causes();
+        let traces: Vec<_> = result.iter().map(|c| c.to_string()).collect();
+        assert_eq!(traces, vec!["[x] a", "[x] a", "[y] b", "[y] b", "[z] a"]);
+        assert_eq!(result[1].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_merge() {
+        let merged = MergedCause::merge(causes());
+        let actual: Vec<_> = merged.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            actual,
+            vec!["b (at [y], [y])", "a (at [x], [z])", "a (at [x])"]
+        );
+        assert_eq!(
+            serde_json::to_value(&merged[1]).unwrap(),
+            serde_json::json!({ "message": "a", "paths": [["x"], ["z"]] })
+        );
+    }
+
+    #[test]
+    fn test_merge_keeps_details() {
+        let merged = MergedCause::merge(vec![
+            Cause::<_, &str>::new("unknown type").trace("post"),
+            Cause::new("unknown type")
+                .trace("comment")
+                .help("did you mean `User`?")
+                .code("type.unknown")
+                .param("name", "Usr"),
+        ]);
+        assert_eq!(
+            serde_json::to_value(&merged).unwrap(),
+            serde_json::json!([{
+                "message": "unknown type",
+                "paths": [["post"], ["comment"]],
+                "help": "did you mean `User`?",
+                "code": "type.unknown",
+                "params": { "name": "Usr" }
+            }])
+        );
+    }
+}
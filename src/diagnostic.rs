@@ -0,0 +1,467 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+use std::ops::Range;
+
+use derive_setters::Setters;
+use serde::{Deserialize, Serialize};
+
+use crate::Cause;
+
+/// A location in a source text that a [`Cause`] points at.
+///
+/// The range is in bytes. Line and column numbers are derived from the text
+/// with [`Span::location`], so a span stays valid however the text is shown.
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{Cause, Span};
+///
+/// let cause: Cause<&str, &str> = Cause::new("must be a number")
+///     .span(Span::new(10..14).file("config.json").label("expected a number"));
+/// assert_eq!(cause.span.unwrap().range, 10..14);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Setters, Serialize, Deserialize)]
+pub struct Span {
+    /// The name of the file the range points into.
+    #[setters(strip_option, into)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// The byte range in the source.
+    pub range: Range<usize>,
+    /// A short text shown next to the underlined source.
+    #[setters(strip_option, into)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl Span {
+    pub fn new(range: Range<usize>) -> Self {
+        Span {
+            file: None,
+            range,
+            label: None,
+        }
+    }
+
+    /// Returns the 1-based line and column of the start of the span.
+    ///
+    /// Columns are counted in characters.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::Span;
+    ///
+    /// let source = "name: api\nport: eighty\n";
+    /// assert_eq!(Span::new(16..22).location(source), (2, 7));
+    /// ```
+    pub fn location(&self, source: &str) -> (usize, usize) {
+        let start = clamp(source, self.range.start);
+        let line_start = line_start(source, start);
+        let line = source[..start].matches('\n').count() + 1;
+        let column = source[line_start..start].chars().count() + 1;
+        (line, column)
+    }
+}
+
+/// Moves an offset back onto the source, at a character boundary.
+fn clamp(source: &str, offset: usize) -> usize {
+    let mut offset = offset.min(source.len());
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+fn line_start(source: &str, offset: usize) -> usize {
+    source[..offset].rfind('\n').map_or(0, |i| i + 1)
+}
+
+fn line_end(source: &str, offset: usize) -> usize {
+    source[offset..]
+        .find('\n')
+        .map_or(source.len(), |i| offset + i)
+}
+
+/// Renders causes as code frames pointing into the validated sources.
+///
+/// Spans without a file point into the text set with [`Renderer::source`],
+/// the others into the texts added with [`Renderer::file`]. Causes whose
+/// source is unknown are rendered without a frame.
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{Cause, Renderer, Span, Valid, Validator};
+///
+/// let source = "name: api\nport: eighty\n";
+/// let cause = Cause::new("must be a number")
+///     .span(Span::new(16..22).file("config.yml").label("not a number"))
+///     .help("use a port between 1 and 65535");
+/// let valid = Valid::<(), &str, &str>::from(cause).trace("port");
+///
+/// let report = Renderer::new()
+///     .file("config.yml", source)
+///     .render(&valid.to_result().unwrap_err());
+/// assert_eq!(
+///     report,
+///     "error: must be a number
+///  --> config.yml:2:7
+///   |
+/// 2 | port: eighty
+///   |       ^^^^^^ not a number
+///   |
+///   = trace: port
+///   = help: use a port between 1 and 65535
+/// "
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Renderer {
+    source: Option<String>,
+    files: HashMap<String, String>,
+}
+
+impl Renderer {
+    pub fn new() -> Self {
+        Renderer::default()
+    }
+
+    /// Sets the text that spans without a file point into.
+    pub fn source(mut self, text: impl Into<String>) -> Self {
+        self.source = Some(text.into());
+        self
+    }
+
+    /// Adds the text of a named file.
+    pub fn file(mut self, name: impl Into<String>, text: impl Into<String>) -> Self {
+        self.files.insert(name.into(), text.into());
+        self
+    }
+
+    /// Renders every cause, separated by blank lines.
+    pub fn render<E: Display, T: Display>(&self, causes: &[Cause<E, T>]) -> String {
+        let mut out = String::new();
+        for (i, cause) in causes.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            self.render_cause(&mut out, cause);
+        }
+        out
+    }
+
+    fn render_cause<E: Display, T: Display>(&self, out: &mut String, cause: &Cause<E, T>) {
+        writeln!(out, "{}: {}", cause.severity, cause.error).unwrap();
+
+        let mut width = 0;
+        let mut has_frame = false;
+        if let Some(span) = &cause.span {
+            let source = match &span.file {
+                Some(file) => self.files.get(file),
+                None => self.source.as_ref(),
+            };
+            match source {
+                Some(source) => {
+                    let (line, column) = span.location(source);
+                    width = line.to_string().len();
+                    has_frame = true;
+
+                    let start = clamp(source, span.range.start);
+                    let line_start = line_start(source, start);
+                    let line_end = line_end(source, start);
+                    let end = clamp(source, span.range.end).clamp(start, line_end);
+                    let text = source[line_start..line_end].trim_end_matches('\r');
+                    let carets = source[start..end].chars().count().max(1);
+
+                    match &span.file {
+                        Some(file) => {
+                            writeln!(out, "{:width$}--> {}:{}:{}", "", file, line, column)
+                        }
+                        None => writeln!(out, "{:width$}--> {}:{}", "", line, column),
+                    }
+                    .unwrap();
+                    writeln!(out, "{:width$} |", "").unwrap();
+                    writeln!(out, "{} | {}", line, text).unwrap();
+                    write!(
+                        out,
+                        "{:width$} | {:pad$}{}",
+                        "",
+                        "",
+                        "^".repeat(carets),
+                        pad = column - 1
+                    )
+                    .unwrap();
+                    match &span.label {
+                        Some(label) => writeln!(out, " {}", label).unwrap(),
+                        None => out.push('\n'),
+                    }
+                }
+                None => {
+                    if let Some(file) = &span.file {
+                        writeln!(out, "--> {}", file).unwrap();
+                    }
+                }
+            }
+        }
+
+        let has_notes = !cause.trace.is_empty() || cause.help.is_some();
+        if has_frame && has_notes {
+            writeln!(out, "{:width$} |", "").unwrap();
+        }
+        if !cause.trace.is_empty() {
+            write!(out, "{:width$} = trace: ", "").unwrap();
+            for (i, entry) in cause.trace.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write!(out, "{}", entry).unwrap();
+            }
+            out.push('\n');
+        }
+        if let Some(help) = &cause.help {
+            writeln!(out, "{:width$} = help: {}", "", help).unwrap();
+        }
+    }
+}
+
+/// Adapts a [`Cause`] to [`miette::Diagnostic`], so that it can be reported
+/// with any of miette's report handlers. The severity, code, help and span of
+/// the cause are passed on to miette.
+///
+/// # Examples
+/// ```
+/// use miette::Diagnostic;
+/// use tailcall_valid::{Cause, CauseDiagnostic, Span};
+///
+/// let cause: Cause<&str, &str> = Cause::new("must be a number").span(Span::new(16..22));
+/// let diagnostic = CauseDiagnostic::new(cause).with_source("config.yml", "name: api\nport: eighty\n");
+/// assert!(diagnostic.source_code().is_some());
+/// assert_eq!(diagnostic.labels().unwrap().count(), 1);
+/// ```
+#[cfg(feature = "miette")]
+#[derive(Debug)]
+pub struct CauseDiagnostic<E, T> {
+    cause: Cause<E, T>,
+    source: Option<miette::NamedSource<String>>,
+}
+
+#[cfg(feature = "miette")]
+impl<E, T> CauseDiagnostic<E, T> {
+    pub fn new(cause: Cause<E, T>) -> Self {
+        CauseDiagnostic {
+            cause,
+            source: None,
+        }
+    }
+
+    /// Attaches the source text the span of the cause points into.
+    pub fn with_source(mut self, name: impl AsRef<str>, text: impl Into<String>) -> Self {
+        self.source = Some(miette::NamedSource::new(name, text.into()));
+        self
+    }
+
+    pub fn into_cause(self) -> Cause<E, T> {
+        self.cause
+    }
+}
+
+#[cfg(feature = "miette")]
+impl<E, T> From<Cause<E, T>> for CauseDiagnostic<E, T> {
+    fn from(cause: Cause<E, T>) -> Self {
+        CauseDiagnostic::new(cause)
+    }
+}
+
+#[cfg(feature = "miette")]
+impl<E: Display, T: Display> Display for CauseDiagnostic<E, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.cause.fmt(f)
+    }
+}
+
+#[cfg(feature = "miette")]
+impl<E: Display + std::fmt::Debug, T: Display + std::fmt::Debug> std::error::Error
+    for CauseDiagnostic<E, T>
+{
+}
+
+#[cfg(feature = "miette")]
+impl<E: Display + std::fmt::Debug, T: Display + std::fmt::Debug> miette::Diagnostic
+    for CauseDiagnostic<E, T>
+{
+    fn severity(&self) -> Option<miette::Severity> {
+        Some(match self.cause.severity {
+            crate::Severity::Error => miette::Severity::Error,
+            crate::Severity::Warning => miette::Severity::Warning,
+            crate::Severity::Info => miette::Severity::Advice,
+        })
+    }
+
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.cause
+            .code
+            .as_ref()
+            .map(|code| Box::new(code) as Box<dyn Display>)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.cause
+            .help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn Display>)
+    }
+
+    fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+        self.source
+            .as_ref()
+            .map(|source| source as &dyn miette::SourceCode)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        let span = self.cause.span.as_ref()?;
+        let label = miette::LabeledSpan::new(
+            span.label.clone(),
+            span.range.start,
+            span.range.end.saturating_sub(span.range.start),
+        );
+        Some(Box::new(std::iter::once(label)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{Renderer, Span};
+    use crate::{Cause, Severity};
+
+    const CONFIG: &str = "{\n  \"name\": \"api\",\n  \"port\": \"80\"\n}\n";
+
+    #[test]
+    fn test_location() {
+        assert_eq!(Span::new(0..1).location(CONFIG), (1, 1));
+        assert_eq!(Span::new(29..33).location(CONFIG), (3, 11));
+        assert_eq!(Span::new(1000..1001).location(CONFIG), (5, 1));
+        assert_eq!(Span::new(7..8).location("\"naïve\": 1"), (1, 7));
+    }
+
+    #[test]
+    fn test_render() {
+        let causes: Vec<Cause<&str, &str>> = vec![
+            Cause::new("must be a number")
+                .span(Span::new(29..33).label("found a string"))
+                .trace("port"),
+            Cause::new("unknown upstream")
+                .severity(Severity::Warning)
+                .span(Span::new(0..5).file("upstreams.json")),
+            Cause::new("name is reserved").help("pick another name"),
+        ];
+        let actual = Renderer::new().source(CONFIG).render(&causes);
+        let expected = [
+            "error: must be a number",
+            " --> 3:11",
+            "  |",
+            "3 |   \"port\": \"80\"",
+            "  |           ^^^^ found a string",
+            "  |",
+            "  = trace: port",
+            "",
+            "warning: unknown upstream",
+            "--> upstreams.json",
+            "",
+            "error: name is reserved",
+            " = help: pick another name",
+            "",
+        ];
+        assert_eq!(actual, expected.join("\n"));
+    }
+
+    #[test]
+    fn test_render_multiline_span() {
+        let causes: Vec<Cause<&str, &str>> =
+            vec![Cause::new("invalid object").span(Span::new(0..CONFIG.len()).file("a.json"))];
+        let actual = Renderer::new().file("a.json", CONFIG).render(&causes);
+        let expected = [
+            "error: invalid object",
+            " --> a.json:1:1",
+            "  |",
+            "1 | {",
+            "  | ^",
+            "",
+        ];
+        assert_eq!(actual, expected.join("\n"));
+    }
+
+    #[test]
+    fn test_serde() {
+        let cause: Cause<String, String> = Cause::new("error".to_string())
+            .span(Span::new(1..3).file("a.json"))
+            .help("fix it");
+        let json = serde_json::to_value(&cause).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "message": "error",
+                "path": [],
+                "span": { "file": "a.json", "range": { "start": 1, "end": 3 } },
+                "help": "fix it"
+            })
+        );
+        assert_eq!(serde_json::from_value::<Cause<_, _>>(json).unwrap(), cause);
+    }
+
+    #[cfg(feature = "miette")]
+    #[test]
+    fn test_miette() {
+        use miette::Diagnostic;
+
+        use super::CauseDiagnostic;
+
+        let cause: Cause<&str, &str> = Cause::new("must be a number")
+            .severity(Severity::Warning)
+            .span(Span::new(29..33).label("found a string"))
+            .help("remove the quotes");
+        let diagnostic = CauseDiagnostic::new(cause).with_source("config.json", CONFIG);
+
+        assert_eq!(diagnostic.severity(), Some(miette::Severity::Warning));
+        assert_eq!(diagnostic.help().unwrap().to_string(), "remove the quotes");
+        let labels: Vec<_> = diagnostic.labels().unwrap().collect();
+        assert_eq!(
+            labels,
+            vec![miette::LabeledSpan::new(
+                Some("found a string".to_string()),
+                29,
+                4
+            )]
+        );
+        assert!(diagnostic.source_code().is_some());
+    }
+
+    #[cfg(feature = "miette")]
+    #[test]
+    fn test_miette_report() {
+        use super::CauseDiagnostic;
+
+        let cause: Cause<&str, &str> = Cause::new("must be a number")
+            .span(Span::new(29..33).label("found a string"))
+            .code("number.expected");
+        let diagnostic = CauseDiagnostic::new(cause).with_source("config.json", CONFIG);
+
+        let mut report = String::new();
+        miette::NarratableReportHandler::new()
+            .render_report(&mut report, &diagnostic)
+            .unwrap();
+        assert_eq!(
+            report,
+            r#"[] must be a number
+    Diagnostic severity: error
+Begin snippet for config.json starting at line 2, column 1
+
+snippet line 2:   "name": "api",
+snippet line 3:   "port": "80"
+    label at line 3, columns 11 to 14: found a string
+snippet line 4: }
+diagnostic code: number.expected
+"#
+        );
+    }
+}
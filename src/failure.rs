@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{Cause, Valid};
+
+/// The failed side of a [`Valid`]: every cause collected during validation.
+///
+/// Serializes as `{"errors": [{"message": .., "path": [..]}, ..]}`, which is
+/// a stable shape suitable for API responses and machine readable output.
+///
+/// Deserializing gives back an equal `Failure`, including the kind of every
+/// [`PathSegment`](crate::PathSegment) in the traces.
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{Failure, Valid, Validator};
+/// let valid = Valid::<(), &str, &str>::fail("must be a string").trace("name");
+/// let failure = Failure::from(valid.to_result().unwrap_err());
+/// assert_eq!(
+///     serde_json::to_string(&failure).unwrap(),
+///     r#"{"errors":[{"message":"must be a string","path":["name"]}]}"#
+/// );
+/// ```
+#[derive(Clone, PartialEq, Debug, Error, Serialize, Deserialize)]
+pub struct Failure<E, T> {
+    pub errors: Vec<Cause<E, T>>,
+}
+
+impl<E: Display, T: Display> Display for Failure<E, T> {
+    /// Writes every cause on its own line.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, cause) in self.errors.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", cause)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E, T> From<Vec<Cause<E, T>>> for Failure<E, T> {
+    fn from(errors: Vec<Cause<E, T>>) -> Self {
+        Failure { errors }
+    }
+}
+
+impl<A, E, T> From<Failure<E, T>> for Valid<A, E, T> {
+    /// Creates a failed validation from a previously collected `Failure`.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Failure, Valid, Validator};
+    /// let failure: Failure<String, String> =
+    ///     serde_json::from_str(r#"{"errors":[{"message":"error","path":[]}]}"#).unwrap();
+    /// let valid: Valid<(), String, String> = Valid::from(failure);
+    /// assert!(valid.is_fail());
+    /// ```
+    fn from(failure: Failure<E, T>) -> Self {
+        Valid::from(failure.errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::Failure;
+    use crate::{Cause, PathSegment, Valid, Validator};
+
+    #[test]
+    fn test_round_trip() {
+        let valid = Valid::<(), String, PathSegment>::fail("error 1".to_string())
+            .trace(2usize)
+            .zip(Valid::<(), _, _>::fail("error 2".to_string()).trace("name"))
+            .trace("users");
+        let failure = Failure::from(valid.to_result().unwrap_err());
+
+        let json = serde_json::to_string(&failure).unwrap();
+        assert_eq!(
+            json,
+            r#"{"errors":[{"message":"error 1","path":["users",2]},{"message":"error 2","path":["users","name"]}]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Failure<String, PathSegment>>(&json).unwrap(),
+            failure
+        );
+    }
+
+    #[test]
+    fn test_round_trip_with_keys() {
+        let failure = Failure::from(vec![Cause::new("error".to_string())
+            .trace(0usize.into())
+            .trace(PathSegment::key("http"))
+            .trace(PathSegment::from("ports"))]);
+
+        let json = serde_json::to_string(&failure).unwrap();
+        assert_eq!(
+            json,
+            r#"{"errors":[{"message":"error","path":["ports",{"key":"http"},0]}]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Failure<String, PathSegment>>(&json).unwrap(),
+            failure
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let failure = Failure::from(vec![
+            Cause::new("error 1").trace("a"),
+            Cause::new("error 2").trace("b"),
+        ]);
+        assert_eq!(failure.to_string(), "[a] error 1\n[b] error 2");
+    }
+}
@@ -0,0 +1,212 @@
+use std::future::Future;
+
+use futures::stream::{self, StreamExt};
+
+use crate::{Valid, Validator};
+
+/// Asynchronous counterparts of the [`Validator`] combinators.
+///
+/// Implemented for every [`Validator`], so bringing this trait into scope is
+/// enough to use it.
+pub trait AsyncValidator<A, E, T>: Validator<A, E, T> {
+    /// Chains an asynchronous validation by applying a function to a
+    /// successful value. If the original validation failed, the errors are
+    /// propagated and `f` is not called.
+    ///
+    /// The returned future is `Send`, so that it can be spawned on a
+    /// multi-threaded runtime such as tokio's. This requires the validation,
+    /// `f` and the future it returns to be `Send` as well.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{AsyncValidator, Valid, Validator};
+    /// # futures::executor::block_on(async {
+    /// let result = Valid::<i32, &str, ()>::succeed(1)
+    ///     .and_then_async(|n| async move { Valid::succeed(n * 2) })
+    ///     .await;
+    /// assert_eq!(result, Valid::succeed(2));
+    /// # });
+    /// ```
+    fn and_then_async<B, Fut>(
+        self,
+        f: impl FnOnce(A) -> Fut + Send,
+    ) -> impl Future<Output = Valid<B, E, T>> + Send
+    where
+        Self: Send,
+        Fut: Future<Output = Valid<B, E, T>> + Send,
+        E: Send,
+        T: Send;
+}
+
+impl<A, E, T, V: Validator<A, E, T>> AsyncValidator<A, E, T> for V {
+    async fn and_then_async<B, Fut>(self, f: impl FnOnce(A) -> Fut + Send) -> Valid<B, E, T>
+    where
+        Self: Send,
+        Fut: Future<Output = Valid<B, E, T>> + Send,
+        E: Send,
+        T: Send,
+    {
+        // The value is handed to `f` before awaiting, so that the future
+        // does not need an `A: Send` bound of its own.
+        let Valid(value, causes) = self.to_valid();
+        let next = value.map(f);
+        match next {
+            Some(next) => next.await.with_causes_before(causes),
+            None => Valid(None, causes),
+        }
+    }
+}
+
+impl<A, E, T> Valid<A, E, T> {
+    /// Validates each item in an iterator using an asynchronous validation
+    /// function, collecting all errors that occur.
+    ///
+    /// At most `concurrency` validations run at the same time (at least one).
+    /// Values and causes are collected in the order of the input, exactly like
+    /// [`Valid::from_iter`].
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    /// # futures::executor::block_on(async {
+    /// let result = Valid::from_iter_async(vec![1, 2, 3], 2, |n| async move {
+    ///     if n % 2 == 0 {
+    ///         Valid::<i32, String, ()>::succeed(n)
+    ///     } else {
+    ///         Valid::fail(format!("{} is odd", n))
+    ///     }
+    /// })
+    /// .await;
+    /// assert_eq!(result.to_result().unwrap_err().len(), 2);
+    /// # });
+    /// ```
+    pub async fn from_iter_async<B, Fut>(
+        iter: impl IntoIterator<Item = A>,
+        concurrency: usize,
+        f: impl FnMut(A) -> Fut,
+    ) -> Valid<Vec<B>, E, T>
+    where
+        Fut: Future<Output = Valid<B, E, T>>,
+    {
+        let results: Vec<Valid<B, E, T>> = stream::iter(iter)
+            .map(f)
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
+        Valid::from_iter(results, |valid| valid)
+    }
+
+    /// Runs two asynchronous validations concurrently and combines their
+    /// results like [`Validator::zip`].
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    /// # futures::executor::block_on(async {
+    /// let result = Valid::zip_async(
+    ///     async { Valid::<i32, &str, ()>::fail("first") },
+    ///     async { Valid::<i32, &str, ()>::fail("second") },
+    /// )
+    /// .await;
+    /// assert_eq!(result.to_result().unwrap_err().len(), 2);
+    /// # });
+    /// ```
+    pub async fn zip_async<A1>(
+        first: impl Future<Output = Valid<A, E, T>>,
+        second: impl Future<Output = Valid<A1, E, T>>,
+    ) -> Valid<(A, A1), E, T> {
+        let (first, second) = futures::join!(first, second);
+        first.zip(second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use pretty_assertions::assert_eq;
+
+    use super::AsyncValidator;
+    use crate::{Cause, Valid, Validator};
+
+    #[tokio::test]
+    async fn test_from_iter_async() {
+        let result = Valid::from_iter_async(1..=5, 3, |n: i32| async move {
+            tokio::task::yield_now().await;
+            if n % 2 == 0 {
+                Valid::<i32, i32, ()>::succeed(n)
+            } else {
+                Valid::fail(n)
+            }
+        })
+        .await;
+        assert_eq!(
+            result,
+            Valid::from(vec![Cause::new(1), Cause::new(3), Cause::new(5)])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_from_iter_async_bounds_concurrency() {
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let result = Valid::from_iter_async(0..20, 4, |n: i32| {
+            let (running, peak) = (&running, &peak);
+            async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::task::yield_now().await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                Valid::<i32, (), ()>::succeed(n)
+            }
+        })
+        .await;
+        assert_eq!(result, Valid::succeed((0..20).collect()));
+        assert_eq!(peak.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_and_then_async() {
+        let result = Valid::<i32, &str, &str>::succeed(1)
+            .warn("w1")
+            .and_then_async(|n| async move { Valid::<i32, _, _>::succeed(n + 1).warn("w2") })
+            .await;
+        assert_eq!(result.clone().to_result(), Ok(2));
+        assert_eq!(result.warnings().len(), 2);
+
+        let result = Valid::<i32, &str, &str>::fail("error")
+            .and_then_async(|n| async move { Valid::<i32, _, _>::succeed(n + 1) })
+            .await;
+        assert_eq!(result, Valid::fail("error"));
+
+        let result = Valid::<i32, &str, &str>::fail("error")
+            .recover(|_| 0)
+            .and_then_async(|_| async { Valid::<i32, _, _>::fail("again") })
+            .await;
+        assert_eq!(
+            result.to_result(),
+            Err(vec![Cause::new("error"), Cause::new("again")])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_and_then_async_spawn() {
+        let valid = Valid::<i32, String, String>::succeed(1).trace("n".to_string());
+        let result = tokio::spawn(valid.and_then_async(|n| async move {
+            tokio::task::yield_now().await;
+            Valid::<i32, _, _>::succeed(n + 1)
+        }))
+        .await
+        .unwrap();
+        assert_eq!(result, Valid::succeed(2));
+    }
+
+    #[tokio::test]
+    async fn test_zip_async() {
+        let result = Valid::zip_async(async { Valid::<i32, &str, ()>::succeed(1) }, async {
+            Valid::<&str, &str, ()>::succeed("ok")
+        })
+        .await;
+        assert_eq!(result, Valid::succeed((1, "ok")));
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::HashSet;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use serde_path_to_error::Segment;
+
+use crate::{Cause, PathSegment, Valid};
+
+/// A step into a JSON document, always relative to the original input.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Step {
+    Key(String),
+    Index(usize),
+}
+
+impl From<Step> for PathSegment {
+    fn from(step: Step) -> Self {
+        match step {
+            Step::Key(key) => PathSegment::Field(key),
+            Step::Index(index) => PathSegment::Index(index),
+        }
+    }
+}
+
+/// Copies `value` leaving out every node whose path is in `removed`.
+fn prune(value: &Value, path: &mut Vec<Step>, removed: &HashSet<Vec<Step>>) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut pruned = serde_json::Map::new();
+            for (key, value) in map {
+                path.push(Step::Key(key.clone()));
+                if !removed.contains(path) {
+                    pruned.insert(key.clone(), prune(value, path, removed));
+                }
+                path.pop();
+            }
+            Value::Object(pruned)
+        }
+        Value::Array(items) => {
+            let mut pruned = Vec::new();
+            for (index, value) in items.iter().enumerate() {
+                path.push(Step::Index(index));
+                if !removed.contains(path) {
+                    pruned.push(prune(value, path, removed));
+                }
+                path.pop();
+            }
+            Value::Array(pruned)
+        }
+        value => value.clone(),
+    }
+}
+
+/// Maps a path reported against the pruned document back onto the original,
+/// accounting for list items that have been removed.
+fn locate(
+    original: &Value,
+    path: &serde_path_to_error::Path,
+    removed: &HashSet<Vec<Step>>,
+) -> Vec<Step> {
+    let mut steps = Vec::new();
+    let mut current = Some(original);
+    for segment in path.iter() {
+        match segment {
+            Segment::Map { key } | Segment::Enum { variant: key } => {
+                current = current.and_then(|value| value.get(key));
+                steps.push(Step::Key(key.clone()));
+            }
+            Segment::Seq { index } => {
+                let len = current.and_then(Value::as_array).map_or(0, Vec::len);
+                let original_index = (0..len)
+                    .filter(|i| {
+                        let mut path = steps.clone();
+                        path.push(Step::Index(*i));
+                        !removed.contains(&path)
+                    })
+                    .nth(*index)
+                    .unwrap_or(*index);
+                current = current.and_then(|value| value.get(original_index));
+                steps.push(Step::Index(original_index));
+            }
+            Segment::Unknown => break,
+        }
+    }
+    steps
+}
+
+/// Extracts the field name from serde's "missing field `name`" message.
+fn missing_field(message: &str) -> Option<&str> {
+    message
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.split('`').next())
+}
+
+impl<A, E, T> Valid<A, E, T>
+where
+    A: DeserializeOwned,
+    E: From<String>,
+    T: From<PathSegment>,
+{
+    /// Deserializes a JSON value, collecting every failure instead of
+    /// stopping at the first one.
+    ///
+    /// Each cause is traced with the path of the offending node in the input.
+    /// After a failure the offending node is set aside and deserialization is
+    /// retried, so that unrelated problems elsewhere in the document are
+    /// reported as well. When a required field is missing, its parent object
+    /// is set aside in the same way.
+    ///
+    /// # Examples
+    /// ```
+    /// use serde::Deserialize;
+    /// use serde_json::json;
+    /// use tailcall_valid::{PathSegment, Valid, Validator};
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// struct User {
+    ///     name: String,
+    ///     age: u8,
+    /// }
+    ///
+    /// let value = json!([{ "name": 1, "age": 20 }, { "name": "bob", "age": -1 }]);
+    /// let result = Valid::<Vec<User>, String, PathSegment>::from_json_value(value);
+    /// let causes = result.to_result().unwrap_err();
+    /// assert_eq!(causes.len(), 2);
+    /// assert_eq!(causes[0].to_string(), "[0, name] invalid type: integer `1`, expected a string");
+    /// assert_eq!(causes[1].to_string(), "[1, age] invalid value: integer `-1`, expected u8");
+    /// ```
+    pub fn from_json_value(value: Value) -> Valid<A, E, T> {
+        let mut removed = HashSet::new();
+        let mut causes: Vec<Cause<E, T>> = Vec::new();
+
+        loop {
+            let pruned = prune(&value, &mut Vec::new(), &removed);
+            let error = match serde_path_to_error::deserialize::<_, A>(pruned) {
+                Ok(a) if causes.is_empty() => return Valid::succeed(a),
+                Ok(_) => return Valid::from(causes),
+                Err(error) => error,
+            };
+
+            let path = locate(&value, error.path(), &removed);
+            let message = error.into_inner().to_string();
+
+            // A missing field can't be set aside, so the object containing it
+            // is removed instead. If the field is only missing because it was
+            // removed earlier, its error has already been reported.
+            let removed_earlier = missing_field(&message).is_some_and(|field| {
+                let mut field_path = path.clone();
+                field_path.push(Step::Key(field.to_string()));
+                removed.contains(&field_path)
+            });
+            if !removed_earlier {
+                causes.push(cause(message, path.clone()));
+            }
+
+            if path.is_empty() || !removed.insert(path) {
+                return Valid::from(causes);
+            }
+        }
+    }
+
+    /// Parses and deserializes a JSON string, collecting every failure.
+    ///
+    /// Syntax errors are reported as a single cause without a trace. See
+    /// [`Valid::from_json_value`] for how the remaining failures are collected.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use tailcall_valid::{PathSegment, Valid, Validator};
+    ///
+    /// let result = Valid::<BTreeMap<String, u32>, String, PathSegment>::from_json_str(
+    ///     r#"{"a": 1, "b": "two", "c": -3}"#,
+    /// );
+    /// let causes = result.to_result().unwrap_err();
+    /// assert_eq!(causes.len(), 2);
+    /// ```
+    pub fn from_json_str(input: &str) -> Valid<A, E, T> {
+        match serde_json::from_str::<Value>(input) {
+            Ok(value) => Valid::from_json_value(value),
+            Err(error) => Valid::fail(E::from(error.to_string())),
+        }
+    }
+}
+
+fn cause<E: From<String>, T: From<PathSegment>>(message: String, path: Vec<Step>) -> Cause<E, T> {
+    path.into_iter()
+        .rev()
+        .fold(Cause::new(E::from(message)), |cause, step| {
+            cause.trace(PathSegment::from(step).into())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::{PathSegment, Valid, Validator};
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Config {
+        name: String,
+        port: u16,
+        upstreams: Vec<Upstream>,
+        #[serde(default)]
+        debug: Option<bool>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Upstream {
+        host: String,
+        timeout: u32,
+    }
+
+    fn errors(value: serde_json::Value) -> Vec<String> {
+        Valid::<Config, String, PathSegment>::from_json_value(value)
+            .to_result()
+            .unwrap_err()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_succeed() {
+        let value = json!({
+            "name": "api",
+            "port": 8000,
+            "upstreams": [{ "host": "a", "timeout": 10 }]
+        });
+        let expected = Config {
+            name: "api".to_string(),
+            port: 8000,
+            upstreams: vec![Upstream {
+                host: "a".to_string(),
+                timeout: 10,
+            }],
+            debug: None,
+        };
+        let actual = Valid::<Config, String, PathSegment>::from_json_value(value);
+        assert_eq!(actual, Valid::succeed(expected));
+    }
+
+    #[test]
+    fn test_collects_all_errors() {
+        let value = json!({
+            "name": 1,
+            "prot": 8000,
+            "port": 8000,
+            "debug": "yes",
+            "upstreams": [
+                { "host": "a", "timeout": "10" },
+                { "host": "b", "timeout": 10 },
+                { "host": 3, "timeout": -1 }
+            ]
+        });
+        let expected = vec![
+            "[debug] invalid type: string \"yes\", expected a boolean",
+            "[name] invalid type: integer `1`, expected a string",
+            "[prot] unknown field `prot`, expected one of `name`, `port`, `upstreams`, `debug`",
+            "[upstreams, 0, timeout] invalid type: string \"10\", expected u32",
+            "[upstreams, 2, host] invalid type: integer `3`, expected a string",
+            "[upstreams, 2, timeout] invalid value: integer `-1`, expected u32",
+        ];
+        assert_eq!(errors(value), expected);
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        let value = json!({
+            "name": "api",
+            "upstreams": [{ "host": "a" }, { "timeout": 1 }, { "host": "c", "timeout": "x" }]
+        });
+        let expected = vec![
+            "[upstreams, 0] missing field `timeout`",
+            "[upstreams, 1] missing field `host`",
+            "[upstreams, 2, timeout] invalid type: string \"x\", expected u32",
+            "[] missing field `port`",
+        ];
+        assert_eq!(errors(value), expected);
+    }
+
+    #[test]
+    fn test_removed_required_field_is_reported_once() {
+        let value = json!({ "name": true, "port": 1, "upstreams": [] });
+        let expected = vec!["[name] invalid type: boolean `true`, expected a string"];
+        assert_eq!(errors(value), expected);
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        let result = Valid::<Config, String, PathSegment>::from_json_str("{\"name\": ");
+        assert_eq!(
+            result,
+            Valid::fail("EOF while parsing a value at line 1 column 9".to_string())
+        );
+    }
+}
@@ -1,8 +1,47 @@
 mod append;
+mod catalog;
 mod cause;
+mod convert;
+mod dedup;
+mod diagnostic;
+mod failure;
+#[cfg(feature = "async")]
+mod future;
+mod json;
+#[cfg(feature = "derive")]
+mod macros;
+#[cfg(feature = "rayon")]
+mod par;
+mod path;
+mod problem;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+pub mod rule;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod tree;
 mod valid;
+#[cfg(feature = "wasm")]
+mod wasm;
 
+// Lets the procedural macros refer to `::tailcall_valid` within this crate.
+#[cfg(feature = "derive")]
+extern crate self as tailcall_valid;
+
+pub use catalog::*;
 pub use cause::*;
+pub use dedup::*;
+pub use diagnostic::*;
+pub use failure::*;
+#[cfg(feature = "async")]
+pub use future::*;
+#[cfg(feature = "derive")]
+pub use macros::*;
+pub use path::*;
+pub use problem::*;
+#[cfg(feature = "derive")]
+pub use tailcall_valid_derive::{ValidFrom, Validate};
+pub use tree::*;
 pub use valid::*;
 
 /// Moral equivalent of TryFrom for validation purposes
@@ -19,6 +58,13 @@ pub trait ValidInto<T> {
     fn valid_into(self) -> Valid<T, Self::Error, Self::Trace>;
 }
 
+/// Checks a value in place, collecting every violation
+pub trait Validate {
+    type Error;
+    type Trace;
+    fn validate(&self) -> Valid<(), Self::Error, Self::Trace>;
+}
+
 /// A blanket implementation for ValidateInto
 impl<S, T: ValidFrom<S>> ValidInto<T> for S {
     type Error = T::Error;
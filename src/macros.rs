@@ -0,0 +1,268 @@
+/// Writes validations in direct style, without nesting `zip` and `and_then`.
+///
+/// The block is made of statements followed by a final expression, which is
+/// the value of the validation:
+///
+/// - `let pattern = validation?;` runs a validation and binds its value. The
+///   pattern can have a type, as in `let port: u16 = port(p)?;`.
+///   Consecutive bindings are independent of each other: all of them are
+///   evaluated and their errors accumulated, as with [`Validator::zip`].
+/// - `then;` separates groups of bindings. The bindings after it can use the
+///   ones before it, and only run if those succeeded, as with
+///   [`Validator::and_then`]. Any other statement separates groups too.
+/// - The final expression is the value of the validation. When it ends with
+///   `?` it is a validation itself, which is returned as is.
+///
+/// The body is parsed as a block by a procedural macro, so it can be as long
+/// as needed without reaching the recursion limit. Requires the `derive`
+/// feature.
+///
+/// [`Validator::zip`]: crate::Validator::zip
+/// [`Validator::and_then`]: crate::Validator::and_then
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{valid, Valid, Validator};
+///
+/// fn name(name: &str) -> Valid<String, &'static str, &'static str> {
+///     match name.is_empty() {
+///         true => Valid::fail("name is required").trace("name"),
+///         false => Valid::succeed(name.to_string()),
+///     }
+/// }
+///
+/// fn port(port: i64) -> Valid<u16, &'static str, &'static str> {
+///     Valid::from_option(u16::try_from(port).ok(), "port is out of range").trace("port")
+/// }
+///
+/// fn address(name: &str, port: u16) -> Valid<String, &'static str, &'static str> {
+///     match port {
+///         0 => Valid::fail("port 0 is reserved"),
+///         port => Valid::succeed(format!("{}:{}", name, port)),
+///     }
+/// }
+///
+/// let parse = |n: &str, p: i64| {
+///     valid! {
+///         let name = name(n)?;
+///         let port = port(p)?;
+///         then;
+///         let address = address(&name, port)?;
+///         (name, address)
+///     }
+/// };
+///
+/// assert_eq!(
+///     parse("api", 80),
+///     Valid::succeed(("api".to_string(), "api:80".to_string()))
+/// );
+///
+/// let causes = parse("", -1).to_result().unwrap_err();
+/// assert_eq!(causes.len(), 2);
+/// assert_eq!(causes[0].to_string(), "[name] name is required");
+/// assert_eq!(causes[1].to_string(), "[port] port is out of range");
+///
+/// assert_eq!(parse("api", 0), Valid::fail("port 0 is reserved"));
+/// ```
+pub use tailcall_valid_derive::valid;
+
+/// Combines any number of validations into a flat tuple of their values,
+/// accumulating the errors of all of them as with [`Validator::zip`].
+///
+/// Unlike chains of [`Validator::fuse`], there is no limit on the number of
+/// validations: the arguments are parsed by a procedural macro, which doesn't
+/// reach the recursion limit however many there are. Requires the `derive`
+/// feature.
+///
+/// [`Validator::zip`]: crate::Validator::zip
+/// [`Validator::fuse`]: crate::Validator::fuse
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{zip_all, Valid, Validator};
+///
+/// let valid = zip_all!(
+///     Valid::<_, &str, ()>::succeed(1),
+///     Valid::succeed("two"),
+///     Valid::succeed(3.0),
+/// );
+/// assert_eq!(valid, Valid::succeed((1, "two", 3.0)));
+///
+/// let valid = zip_all!(
+///     Valid::<i32, _, ()>::fail("first"),
+///     Valid::<i32, _, _>::succeed(2),
+///     Valid::<i32, _, _>::fail("third"),
+/// );
+/// assert_eq!(valid.to_result().unwrap_err().len(), 2);
+/// ```
+pub use tailcall_valid_derive::zip_all;
+
+/// Combines any number of validations like [`zip_all!`] and passes their
+/// values to a function.
+/// Requires the `derive` feature.
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{zip_with, Valid, Validator};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Server {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// let valid = zip_with!(
+///     Valid::<_, &str, ()>::succeed("localhost".to_string()),
+///     Valid::succeed(8080),
+///     => |host, port| Server { host, port }
+/// );
+/// assert_eq!(
+///     valid,
+///     Valid::succeed(Server { host: "localhost".to_string(), port: 8080 })
+/// );
+/// ```
+#[macro_export]
+macro_rules! zip_with {
+    ($($e:expr),+ $(,)? => |$($p:pat_param),* $(,)?| $body:expr) => {
+        $crate::Validator::map($crate::zip_all!($($e),+), |($($p,)*)| $body)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{Cause, Valid, Validator};
+
+    fn positive(n: i32) -> Valid<i32, String, ()> {
+        match n {
+            n if n > 0 => Valid::succeed(n),
+            n => Valid::fail(format!("{} is not positive", n)),
+        }
+    }
+
+    #[test]
+    fn test_accumulates_independent_bindings() {
+        let mut calls = 0;
+        let result: Valid<i32, String, ()> = crate::valid! {
+            let a = positive(-1)?;
+            let (b, c) = positive(2).zip(positive(-3))?;
+            then;
+            let d = {
+                calls += 1;
+                positive(a + b + c)
+            }?;
+            d
+        };
+        assert_eq!(calls, 0);
+        assert_eq!(
+            result,
+            Valid::from(vec![
+                Cause::new("-1 is not positive".to_string()),
+                Cause::new("-3 is not positive".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn test_typed_bindings() {
+        let result: Valid<i32, String, ()> = crate::valid! {
+            let a: i32 = positive(1)?;
+            let (b, c): (i32, i32) = positive(2).zip(positive(3))?;
+            a + b + c
+        };
+        assert_eq!(result, Valid::succeed(6));
+    }
+
+    #[test]
+    fn test_statements() {
+        let result: Valid<i32, String, ()> = crate::valid! {
+            let a = positive(1)?;
+            let b = a * 2;
+            assert_eq!(b, 2);
+            let c = positive(b - 3)?;
+            c
+        };
+        assert_eq!(result, Valid::fail("-1 is not positive".to_string()));
+    }
+
+    #[test]
+    fn test_final_validation() {
+        let result = crate::valid! {
+            let a = positive(1)?;
+            let b = positive(2)?;
+            positive(a - b).trace(())?
+        };
+        assert_eq!(
+            result,
+            Valid::from(vec![Cause::new("-1 is not positive".to_string()).trace(())])
+        );
+    }
+
+    #[test]
+    fn test_keeps_warnings() {
+        let result: Valid<i32, String, ()> = crate::valid! {
+            let a = positive(1).warn("first".to_string())?;
+            then;
+            let b = positive(a + 1).warn("second".to_string())?;
+            a + b
+        };
+        let warnings: Vec<&str> = result.warnings().iter().map(|w| w.error.as_str()).collect();
+        assert_eq!(warnings, vec!["first", "second"]);
+        assert_eq!(result.to_result(), Ok(3));
+    }
+
+    #[test]
+    fn test_empty() {
+        let result: Valid<(), String, ()> = crate::valid! {};
+        assert_eq!(result, Valid::succeed(()));
+    }
+
+    #[test]
+    fn test_zip_all() {
+        let result: Valid<_, String, ()> = crate::zip_all!(
+            positive(1),
+            positive(2),
+            positive(3),
+            positive(4),
+            positive(5),
+            positive(6),
+            positive(7),
+            positive(8),
+            positive(9),
+            positive(10),
+            positive(11),
+            positive(12),
+            positive(13),
+        );
+        let (a, b, .., m) = result.to_result().unwrap();
+        assert_eq!((a, b, m), (1, 2, 13));
+
+        let result = crate::zip_all!(positive(-1).warn("first".to_string()));
+        assert_eq!(result.warnings().len(), 1);
+        assert_eq!(
+            result.to_result(),
+            Err(vec![Cause::new("-1 is not positive".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_zip_with() {
+        let result = crate::zip_with!(
+            positive(-1),
+            positive(2),
+            positive(-3)
+            => |a, b, c| a + b + c
+        );
+        assert_eq!(
+            result,
+            Valid::from(vec![
+                Cause::new("-1 is not positive".to_string()),
+                Cause::new("-3 is not positive".to_string())
+            ])
+        );
+
+        let result = crate::zip_with!(positive(1), positive(2) => |a, b| a * 10 + b);
+        assert_eq!(result, Valid::succeed(12));
+    }
+}
@@ -0,0 +1,74 @@
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::Valid;
+
+impl<A: Send, E: Send, T: Send> Valid<A, E, T> {
+    /// Validates each item in parallel using the provided validation function,
+    /// collecting all errors that occur.
+    ///
+    /// Behaves exactly like [`Valid::from_iter`]: values and causes are
+    /// collected in the order of the input, regardless of the order in which
+    /// the items are validated.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    /// let result = Valid::par_from_iter(vec![1, 2, 3, 4], |n| {
+    ///     if n % 2 == 0 {
+    ///         Valid::<i32, String, ()>::succeed(n * 2)
+    ///     } else {
+    ///         Valid::<i32, String, ()>::fail(format!("{} is odd", n))
+    ///     }
+    /// });
+    /// let causes = result.to_result().unwrap_err();
+    /// assert_eq!(causes[0].error, "1 is odd");
+    /// assert_eq!(causes[1].error, "3 is odd");
+    /// ```
+    pub fn par_from_iter<B: Send>(
+        iter: impl IntoParallelIterator<Item = A>,
+        f: impl Fn(A) -> Valid<B, E, T> + Sync + Send,
+    ) -> Valid<Vec<B>, E, T> {
+        let results: Vec<Valid<B, E, T>> = iter.into_par_iter().map(f).collect();
+        Valid::from_iter(results, |valid| valid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{Valid, Validator};
+
+    fn check(n: usize) -> Valid<usize, String, usize> {
+        if n.is_multiple_of(3) {
+            Valid::fail(format!("{} is divisible by 3", n)).trace(n)
+        } else {
+            Valid::succeed(n * 2)
+        }
+    }
+
+    #[test]
+    fn test_same_as_from_iter() {
+        let input: Vec<usize> = (0..10_000).collect();
+        let expected = Valid::from_iter(input.clone(), check);
+        let actual = Valid::par_from_iter(input, check);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_preserves_order() {
+        let input: Vec<usize> = (1..10_000usize).filter(|n| !n.is_multiple_of(3)).collect();
+        let actual = Valid::par_from_iter(input.clone(), check);
+        let expected: Vec<usize> = input.iter().map(|n| n * 2).collect();
+        assert_eq!(actual, Valid::succeed(expected));
+    }
+
+    #[test]
+    fn test_keeps_warnings() {
+        let actual = Valid::par_from_iter(0..4usize, |n| {
+            Valid::<usize, String, ()>::succeed(n).warn(n.to_string())
+        });
+        let warnings: Vec<&str> = actual.warnings().iter().map(|w| w.error.as_str()).collect();
+        assert_eq!(warnings, vec!["0", "1", "2", "3"]);
+    }
+}
@@ -0,0 +1,277 @@
+use std::fmt::{Display, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Cause;
+
+/// A single step in the trace of a [`Cause`], pointing into structured data.
+///
+/// Strings convert into [`PathSegment::Field`] and integers into
+/// [`PathSegment::Index`], so `Validator::trace("name")` and
+/// `Validator::trace(3usize)` both work when the trace type is `PathSegment`.
+///
+/// Fields serialize as a plain string, indices as a number and keys as
+/// `{"key": ..}`, so every segment deserializes back to the same variant.
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::PathSegment;
+/// let path = vec![PathSegment::from("headers"), PathSegment::key("x-api-key"), 0.into()];
+/// let json = serde_json::to_string(&path).unwrap();
+/// assert_eq!(json, r#"["headers",{"key":"x-api-key"},0]"#);
+/// assert_eq!(serde_json::from_str::<Vec<PathSegment>>(&json).unwrap(), path);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(from = "Repr", into = "Repr")]
+pub enum PathSegment {
+    /// A named field of a struct or object.
+    Field(String),
+    /// A position in a list.
+    Index(usize),
+    /// A key in a map.
+    Key(String),
+}
+
+/// The wire shape of a [`PathSegment`].
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Repr {
+    Field(String),
+    Index(usize),
+    Key { key: String },
+}
+
+impl From<Repr> for PathSegment {
+    fn from(value: Repr) -> Self {
+        match value {
+            Repr::Field(name) => PathSegment::Field(name),
+            Repr::Index(index) => PathSegment::Index(index),
+            Repr::Key { key } => PathSegment::Key(key),
+        }
+    }
+}
+
+impl From<PathSegment> for Repr {
+    fn from(value: PathSegment) -> Self {
+        match value {
+            PathSegment::Field(name) => Repr::Field(name),
+            PathSegment::Index(index) => Repr::Index(index),
+            PathSegment::Key(key) => Repr::Key { key },
+        }
+    }
+}
+
+impl PathSegment {
+    /// Creates a segment for the key of a map entry.
+    pub fn key(key: impl Display) -> Self {
+        PathSegment::Key(key.to_string())
+    }
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathSegment::Field(name) | PathSegment::Key(name) => write!(f, "{}", name),
+            PathSegment::Index(index) => write!(f, "{}", index),
+        }
+    }
+}
+
+impl From<&str> for PathSegment {
+    fn from(value: &str) -> Self {
+        PathSegment::Field(value.to_string())
+    }
+}
+
+impl From<String> for PathSegment {
+    fn from(value: String) -> Self {
+        PathSegment::Field(value)
+    }
+}
+
+impl From<&String> for PathSegment {
+    fn from(value: &String) -> Self {
+        PathSegment::Field(value.clone())
+    }
+}
+
+impl From<usize> for PathSegment {
+    fn from(value: usize) -> Self {
+        PathSegment::Index(value)
+    }
+}
+
+impl From<PathSegment> for String {
+    fn from(value: PathSegment) -> Self {
+        value.to_string()
+    }
+}
+
+/// Returns true if the name can be written after a `.` without quoting.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' || c == '$' => {
+            chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        }
+        _ => false,
+    }
+}
+
+impl<E> Cause<E, PathSegment> {
+    /// Renders the trace as a dotted path such as `users[3].email`.
+    ///
+    /// Fields and map keys that are not plain identifiers are quoted, eg.
+    /// `headers["content-type"]`.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Cause, PathSegment};
+    /// let cause = Cause::new("must be a string")
+    ///     .trace("email".into())
+    ///     .trace(3.into())
+    ///     .trace("users".into());
+    /// assert_eq!(cause.to_dotted_path(), "users[3].email");
+    /// ```
+    pub fn to_dotted_path(&self) -> String {
+        let mut path = String::new();
+        for segment in &self.trace {
+            match segment {
+                PathSegment::Index(index) => write!(path, "[{}]", index).unwrap(),
+                PathSegment::Field(name) | PathSegment::Key(name) if !is_identifier(name) => {
+                    write!(path, "[{}]", serde_json::Value::from(name.as_str())).unwrap()
+                }
+                PathSegment::Field(name) | PathSegment::Key(name) => {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(name);
+                }
+            }
+        }
+        path
+    }
+
+    /// Renders the trace as a JSON Pointer as defined by RFC 6901.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Cause, PathSegment};
+    /// let cause = Cause::new("must be a string")
+    ///     .trace(PathSegment::key("a/b"))
+    ///     .trace(0.into());
+    /// assert_eq!(cause.to_json_pointer(), "/0/a~1b");
+    /// ```
+    pub fn to_json_pointer(&self) -> String {
+        let mut pointer = String::new();
+        for segment in &self.trace {
+            pointer.push('/');
+            pointer.push_str(&segment.to_string().replace('~', "~0").replace('/', "~1"));
+        }
+        pointer
+    }
+
+    /// Renders the trace as a JSONPath expression such as `$.users[3].email`.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Cause, PathSegment};
+    /// let cause = Cause::new("must be a string")
+    ///     .trace(PathSegment::key("x-api-key"))
+    ///     .trace("headers".into());
+    /// assert_eq!(cause.to_json_path(), "$.headers['x-api-key']");
+    /// ```
+    pub fn to_json_path(&self) -> String {
+        let mut path = String::from("$");
+        for segment in &self.trace {
+            match segment {
+                PathSegment::Index(index) => write!(path, "[{}]", index).unwrap(),
+                PathSegment::Field(name) | PathSegment::Key(name) if is_identifier(name) => {
+                    write!(path, ".{}", name).unwrap()
+                }
+                PathSegment::Field(name) | PathSegment::Key(name) => {
+                    let name = name.replace('\\', "\\\\").replace('\'', "\\'");
+                    write!(path, "['{}']", name).unwrap()
+                }
+            }
+        }
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::PathSegment;
+    use crate::{Cause, Valid, Validator};
+
+    fn cause_at(path: Vec<PathSegment>) -> Cause<&'static str, PathSegment> {
+        path.into_iter()
+            .rev()
+            .fold(Cause::new("error"), |cause, segment| cause.trace(segment))
+    }
+
+    #[test]
+    fn test_into_segment() {
+        let result = Valid::<(), &str, PathSegment>::fail("error")
+            .trace("email")
+            .trace(3usize)
+            .trace("users");
+        let causes = result.to_result().unwrap_err();
+        assert_eq!(
+            causes[0].trace,
+            vec![
+                PathSegment::Field("users".to_string()),
+                PathSegment::Index(3),
+                PathSegment::Field("email".to_string())
+            ]
+        );
+        assert_eq!(causes[0].to_string(), "[users, 3, email] error");
+    }
+
+    #[test]
+    fn test_dotted_path() {
+        let cause = cause_at(vec![
+            "types".into(),
+            PathSegment::key("User"),
+            "fields".into(),
+            PathSegment::key("first name"),
+            0.into(),
+        ]);
+        assert_eq!(
+            cause.to_dotted_path(),
+            r#"types.User.fields["first name"][0]"#
+        );
+    }
+
+    #[test]
+    fn test_dotted_path_quotes_fields() {
+        let cause = cause_at(vec!["headers".into(), "content-type".into(), "0".into()]);
+        assert_eq!(cause.to_dotted_path(), r#"headers["content-type"]["0"]"#);
+    }
+
+    #[test]
+    fn test_dotted_path_starting_with_index() {
+        let cause = cause_at(vec![1.into(), "name".into()]);
+        assert_eq!(cause.to_dotted_path(), "[1].name");
+    }
+
+    #[test]
+    fn test_json_pointer() {
+        let cause = cause_at(vec![
+            "paths".into(),
+            PathSegment::key("/users/{id}"),
+            PathSegment::key("a~b"),
+        ]);
+        assert_eq!(cause.to_json_pointer(), "/paths/~1users~1{id}/a~0b");
+        assert_eq!(cause_at(vec![]).to_json_pointer(), "");
+    }
+
+    #[test]
+    fn test_json_path() {
+        let cause = cause_at(vec!["users".into(), 2.into(), PathSegment::key("it's")]);
+        assert_eq!(cause.to_json_path(), r"$.users[2]['it\'s']");
+        assert_eq!(cause_at(vec![]).to_json_path(), "$");
+    }
+}
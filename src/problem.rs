@@ -0,0 +1,217 @@
+use derive_setters::Setters;
+use http::header::{HeaderValue, CONTENT_TYPE};
+use http::{Response, StatusCode};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{Cause, Failure};
+
+/// Turns validation failures into RFC 7807 `application/problem+json`
+/// responses.
+///
+/// Every cause is listed in the `errors` member of the problem document, in
+/// the same shape as it serializes to elsewhere. The status defaults to
+/// `422 Unprocessable Entity`.
+///
+/// # Examples
+/// ```
+/// use http::StatusCode;
+/// use tailcall_valid::{Problem, Valid, Validator};
+///
+/// let valid = Valid::<(), &str, &str>::fail("must be a string").trace("name");
+/// let causes = valid.to_result().unwrap_err();
+/// let response = Problem::default()
+///     .status(StatusCode::BAD_REQUEST)
+///     .response(&causes);
+///
+/// assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+/// assert_eq!(response.headers()["content-type"], "application/problem+json");
+/// ```
+#[derive(Clone, Debug, Setters)]
+#[setters(into)]
+pub struct Problem {
+    /// The HTTP status of the response.
+    pub status: StatusCode,
+    /// A URI reference identifying the problem type.
+    pub type_uri: String,
+    /// A short, human readable summary of the problem type.
+    pub title: String,
+    /// A URI reference identifying this occurrence of the problem.
+    #[setters(strip_option)]
+    pub instance: Option<String>,
+}
+
+impl Default for Problem {
+    fn default() -> Self {
+        Problem {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            type_uri: "about:blank".to_string(),
+            title: "Validation failed".to_string(),
+            instance: None,
+        }
+    }
+}
+
+impl Problem {
+    /// Builds a problem response listing every cause, serialized as by
+    /// [`Cause`]'s `Serialize` implementation.
+    ///
+    /// If the causes can't be serialized, the response is a
+    /// `500 Internal Server Error` problem describing why.
+    pub fn response<E: Serialize, T: Serialize>(&self, causes: &[Cause<E, T>]) -> Response<String> {
+        let errors = match serde_json::to_value(causes) {
+            Ok(errors) => errors,
+            Err(error) => return Problem::serialization_failure(error),
+        };
+        let detail = match causes.len() {
+            1 => "1 validation error".to_string(),
+            n => format!("{} validation errors", n),
+        };
+
+        let mut body = json!({
+            "type": self.type_uri,
+            "title": self.title,
+            "status": self.status.as_u16(),
+            "detail": detail,
+            "errors": errors,
+        });
+        if let Some(instance) = &self.instance {
+            body["instance"] = json!(instance);
+        }
+        self.build(body)
+    }
+
+    /// The response sent in place of causes that failed to serialize.
+    fn serialization_failure(error: serde_json::Error) -> Response<String> {
+        let problem = Problem::default()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .title("Failed to serialize the validation errors");
+        let body = json!({
+            "type": problem.type_uri,
+            "title": problem.title,
+            "status": problem.status.as_u16(),
+            "detail": error.to_string(),
+        });
+        problem.build(body)
+    }
+
+    fn build(&self, body: serde_json::Value) -> Response<String> {
+        let mut response = Response::new(body.to_string());
+        *response.status_mut() = self.status;
+        response.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/problem+json"),
+        );
+        response
+    }
+}
+
+impl<E: Serialize, T: Serialize> From<Failure<E, T>> for Response<String> {
+    /// Builds a problem response with the default [`Problem`] settings.
+    ///
+    /// # Examples
+    /// ```
+    /// use http::Response;
+    /// use tailcall_valid::{Cause, Failure};
+    ///
+    /// let failure: Failure<&str, &str> = Failure::from(vec![Cause::new("error")]);
+    /// let response = Response::from(failure);
+    /// assert_eq!(response.status(), 422);
+    /// ```
+    fn from(failure: Failure<E, T>) -> Self {
+        Problem::default().response(&failure.errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::Problem;
+    use crate::{Cause, PathSegment, Valid, Validator};
+
+    #[test]
+    fn test_response() {
+        let valid = Valid::<(), &str, PathSegment>::fail("must be a string")
+            .trace("email")
+            .trace(0usize)
+            .zip(Valid::<(), _, _>::fail("is required").trace("name"))
+            .trace("users");
+        let causes = valid.to_result().unwrap_err();
+
+        let response = Problem::default().instance("/users").response(&causes);
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            response.headers()["content-type"],
+            "application/problem+json"
+        );
+        let body: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "type": "about:blank",
+                "title": "Validation failed",
+                "status": 422,
+                "detail": "2 validation errors",
+                "instance": "/users",
+                "errors": [
+                    { "message": "must be a string", "path": ["users", 0, "email"] },
+                    { "message": "is required", "path": ["users", "name"] }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_custom_problem() {
+        let causes = Valid::<(), &str, &str>::fail("error")
+            .to_result()
+            .unwrap_err();
+        let response = Problem::default()
+            .status(StatusCode::BAD_REQUEST)
+            .type_uri("https://example.com/problems/invalid-config")
+            .title("Invalid configuration")
+            .response(&causes);
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert_eq!(body["type"], "https://example.com/problems/invalid-config");
+        assert_eq!(body["title"], "Invalid configuration");
+        assert_eq!(body["status"], 400);
+        assert_eq!(body["detail"], "1 validation error");
+    }
+
+    #[test]
+    fn test_keeps_cause_details() {
+        let cause = Cause::new("is too long")
+            .trace("name")
+            .help("use at most 8 characters")
+            .code("string.too_long")
+            .param("max", 8);
+        let response = Problem::default().response(&[cause]);
+        let body: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert_eq!(
+            body["errors"],
+            json!([{
+                "message": "is too long",
+                "path": ["name"],
+                "help": "use at most 8 characters",
+                "code": "string.too_long",
+                "params": { "max": "8" }
+            }])
+        );
+    }
+
+    #[test]
+    fn test_serialization_failure() {
+        let key = |n: u8| std::collections::BTreeMap::from([(vec![n], n)]);
+        let causes = vec![Cause::<_, &str>::new(key(1))];
+        let response = Problem::default().response(&causes);
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        let body: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert_eq!(body["title"], "Failed to serialize the validation errors");
+    }
+}
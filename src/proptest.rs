@@ -0,0 +1,141 @@
+//! [`proptest`] strategies for causes and validations.
+//!
+//! [`Cause`], [`Severity`] and [`Valid`] implement [`Arbitrary`], and the
+//! functions of this module build strategies from strategies of their parts.
+//! [`laws`] checks that validators obey the laws the combinators of
+//! [`Validator`](crate::Validator) rely on.
+//!
+//! # Examples
+//! ```
+//! use proptest::prelude::*;
+//! use tailcall_valid::{Valid, Validator};
+//!
+//! proptest!(|(a: Valid<u8, u8, u8>, b: Valid<u8, u8, u8>)| {
+//!     prop_assert_eq!(a.clone().zip(b.clone()).is_succeed(), a.is_succeed() && b.is_succeed());
+//! });
+//! ```
+
+pub mod laws;
+
+use std::fmt::Debug;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+use crate::{Cause, Severity, Valid};
+
+/// Generates causes of any severity, with up to three trace segments.
+///
+/// # Examples
+/// ```
+/// use proptest::prelude::*;
+/// use tailcall_valid::proptest::cause;
+///
+/// proptest!(|(cause in cause("[a-z]+", 0..10usize))| {
+///     prop_assert!(cause.trace.len() <= 3);
+/// });
+/// ```
+pub fn cause<E: Debug, T: Debug>(
+    error: impl Strategy<Value = E>,
+    trace: impl Strategy<Value = T>,
+) -> impl Strategy<Value = Cause<E, T>> {
+    (error, vec(trace, 0..=3), any::<Severity>()).prop_map(|(error, trace, severity)| {
+        let mut cause = Cause::new(error).severity(severity);
+        cause.trace = trace.into();
+        cause
+    })
+}
+
+/// Generates validations with up to three causes of any severity, that either
+/// have a value or fail with at least one error. Validations with a value and
+/// errors are the ones that recovered with [`Valid::recover`].
+///
+/// # Examples
+/// ```
+/// use proptest::prelude::*;
+/// use tailcall_valid::proptest::valid;
+/// use tailcall_valid::Validator;
+///
+/// proptest!(|(valid in valid(0..100u32, "[a-z]+", "[a-z]+"))| {
+///     if let Ok(value) = valid.to_result() {
+///         prop_assert!(value < 100);
+///     }
+/// });
+/// ```
+pub fn valid<A: Debug, E: Debug, T: Debug>(
+    value: impl Strategy<Value = A>,
+    error: impl Strategy<Value = E> + Clone,
+    trace: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Valid<A, E, T>> {
+    let failure = cause(error.clone(), trace.clone()).prop_map(|c| c.severity(Severity::Error));
+    (
+        proptest::option::of(value),
+        vec(cause(error, trace), 0..=2),
+        failure,
+        any::<Index>(),
+    )
+        .prop_map(|(value, mut causes, failure, index)| {
+            if value.is_none() {
+                causes.insert(index.index(causes.len() + 1), failure);
+            }
+            Valid(value, causes)
+        })
+}
+
+impl Arbitrary for Severity {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            Just(Severity::Error),
+            Just(Severity::Warning),
+            Just(Severity::Info)
+        ]
+        .boxed()
+    }
+}
+
+impl<E, T> Arbitrary for Cause<E, T>
+where
+    E: Arbitrary + 'static,
+    T: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        cause(any::<E>(), any::<T>()).boxed()
+    }
+}
+
+impl<A, E, T> Arbitrary for Valid<A, E, T>
+where
+    A: Arbitrary + 'static,
+    E: Arbitrary + 'static,
+    T: Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        valid(any::<A>(), any::<E>().boxed(), any::<T>().boxed()).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::{Severity, Valid};
+
+    proptest! {
+        #[test]
+        fn test_valid_without_value_has_errors(valid: Valid<u8, u8, u8>) {
+            let has_errors = valid.causes().iter().any(|cause| cause.severity == Severity::Error);
+            prop_assert!(valid.0.is_some() || has_errors);
+            prop_assert!(valid.causes().len() <= 3);
+        }
+    }
+}
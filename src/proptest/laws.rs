@@ -0,0 +1,316 @@
+//! Laws that every [`Validator`] is expected to obey.
+//!
+//! Each law takes the validations to check and returns a [`TestCaseError`]
+//! when they break it, so that it can be used inside `proptest!`.
+//! [`check_laws`] runs every law that doesn't need a function against
+//! generated validators.
+//!
+//! The validator under test is the receiver of the combinators, so that
+//! methods it overrides are checked too. Results are compared after
+//! [`Validator::to_valid`]: values, errors and diagnostics, along with their
+//! order, must all be the same.
+//!
+//! # Examples
+//! ```
+//! use proptest::prelude::*;
+//! use tailcall_valid::proptest::laws;
+//! use tailcall_valid::Valid;
+//!
+//! proptest!(|(v: Valid<u8, u8, u8>, b: Valid<u8, u8, u8>)| {
+//!     laws::and_then_associativity(v, |n| Valid::succeed(n / 2), move |_| b.clone())?;
+//! });
+//! ```
+
+use std::fmt::Debug;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::test_runner::{TestCaseError, TestRunner};
+
+use crate::{Cause, Valid, Validator};
+
+/// `v.map(|a| a)` is `v`.
+pub fn map_identity<A, E, T, V>(v: V) -> Result<(), TestCaseError>
+where
+    A: Clone + PartialEq + Debug,
+    E: Clone + PartialEq + Debug,
+    T: Clone + PartialEq + Debug,
+    V: Validator<A, E, T> + Clone,
+{
+    prop_assert_eq!(v.clone().map(|a| a), v.to_valid());
+    Ok(())
+}
+
+/// Zipping with a success on either side only pairs the value with `()`.
+pub fn zip_identity<A, E, T, V>(v: V) -> Result<(), TestCaseError>
+where
+    A: Clone + PartialEq + Debug,
+    E: Clone + PartialEq + Debug,
+    T: Clone + PartialEq + Debug,
+    V: Validator<A, E, T> + Clone,
+{
+    let left = Valid::succeed(())
+        .zip(v.clone().to_valid())
+        .map(|((), a)| a);
+    prop_assert_eq!(left, v.clone().to_valid());
+    let right = v.clone().zip(Valid::succeed(())).map(|(a, ())| a);
+    prop_assert_eq!(right, v.to_valid());
+    Ok(())
+}
+
+/// `a.zip(b.zip(c))` and `a.zip(b).zip(c)` have the same values, errors and
+/// diagnostics, in the same order.
+pub fn zip_associativity<A, E, T, V>(a: V, b: V, c: V) -> Result<(), TestCaseError>
+where
+    A: Clone + PartialEq + Debug,
+    E: Clone + PartialEq + Debug,
+    T: Clone + PartialEq + Debug,
+    V: Validator<A, E, T> + Clone,
+{
+    let (b, c) = (b.to_valid(), c.to_valid());
+    let right = a
+        .clone()
+        .zip(b.clone().zip(c.clone()))
+        .map(|(a, (b, c))| (a, b, c));
+    let left = a.zip(b).zip(c).map(|((a, b), c)| (a, b, c));
+    prop_assert_eq!(left, right);
+    Ok(())
+}
+
+/// `a.zip(b)` keeps every error and diagnostic of `a` followed by every error
+/// and diagnostic of `b`, and only succeeds if both succeed.
+pub fn zip_error_order<A, E, T, V>(a: V, b: V) -> Result<(), TestCaseError>
+where
+    A: Clone + PartialEq + Debug,
+    E: Clone + PartialEq + Debug,
+    T: Clone + PartialEq + Debug,
+    V: Validator<A, E, T> + Clone,
+{
+    let zipped = observe(a.clone().zip(b.clone().to_valid()));
+    let (a, b) = (observe(a.to_valid()), observe(b.to_valid()));
+
+    prop_assert_eq!(zipped.0, a.0.zip(b.0));
+    let expected: Vec<_> = a.1.into_iter().chain(b.1).collect();
+    prop_assert_eq!(zipped.1, expected);
+    let expected: Vec<_> = a.2.into_iter().chain(b.2).collect();
+    prop_assert_eq!(zipped.2, expected);
+    Ok(())
+}
+
+/// What a validation reports: its value if it succeeds, its errors and its
+/// diagnostics.
+#[allow(clippy::type_complexity)]
+fn observe<A, E: Clone, T: Clone>(
+    valid: Valid<A, E, T>,
+) -> (Option<A>, Vec<Cause<E, T>>, Vec<Cause<E, T>>) {
+    let diagnostics = valid.warnings().into_iter().cloned().collect();
+    match valid.to_result() {
+        Ok(a) => (Some(a), Vec::new(), diagnostics),
+        Err(errors) => (None, errors, diagnostics),
+    }
+}
+
+/// Tracing a zip is the same as zipping the traced validations.
+pub fn trace_distributes_over_zip<A, E, T, V>(a: V, b: V, trace: T) -> Result<(), TestCaseError>
+where
+    A: Clone + PartialEq + Debug,
+    E: Clone + PartialEq + Debug,
+    T: Clone + PartialEq + Debug,
+    V: Validator<A, E, T> + Clone,
+{
+    let left = a.clone().zip(b.clone().to_valid()).trace(trace.clone());
+    let right = a.trace(trace.clone()).zip(b.trace(trace));
+    prop_assert_eq!(left, right);
+    Ok(())
+}
+
+/// `a.and(b)` and `a.fuse(b)` are `a.zip(b)`, keeping the second value and
+/// the pair respectively.
+pub fn and_fuse_match_zip<A, E, T, V>(a: V, b: V) -> Result<(), TestCaseError>
+where
+    A: Clone + PartialEq + Debug,
+    E: Clone + PartialEq + Debug,
+    T: Clone + PartialEq + Debug,
+    V: Validator<A, E, T> + Clone,
+{
+    let b = b.to_valid();
+    let zipped = a.clone().zip(b.clone());
+    prop_assert_eq!(a.clone().and(b.clone()), zipped.clone().map(|(_, b)| b));
+    prop_assert_eq!(a.fuse(b).to_valid(), zipped);
+    Ok(())
+}
+
+/// `Valid::succeed(a).and_then(f)` is `f(a)`.
+pub fn and_then_left_identity<A, B, E, T>(
+    a: A,
+    f: impl Fn(A) -> Valid<B, E, T>,
+) -> Result<(), TestCaseError>
+where
+    A: Clone,
+    B: PartialEq + Debug,
+    E: PartialEq + Debug,
+    T: PartialEq + Debug,
+{
+    prop_assert_eq!(Valid::succeed(a.clone()).and_then(&f), f(a));
+    Ok(())
+}
+
+/// `v.and_then(Valid::succeed)` is `v`.
+pub fn and_then_right_identity<A, E, T, V>(v: V) -> Result<(), TestCaseError>
+where
+    A: Clone + PartialEq + Debug,
+    E: Clone + PartialEq + Debug,
+    T: Clone + PartialEq + Debug,
+    V: Validator<A, E, T> + Clone,
+{
+    prop_assert_eq!(v.clone().and_then(Valid::succeed), v.to_valid());
+    Ok(())
+}
+
+/// `v.and_then(f).and_then(g)` is `v.and_then(|a| f(a).and_then(g))`.
+pub fn and_then_associativity<A, B, C, E, T, V>(
+    v: V,
+    f: impl Fn(A) -> Valid<B, E, T>,
+    g: impl Fn(B) -> Valid<C, E, T>,
+) -> Result<(), TestCaseError>
+where
+    C: PartialEq + Debug,
+    E: Clone + PartialEq + Debug,
+    T: Clone + PartialEq + Debug,
+    V: Validator<A, E, T> + Clone,
+{
+    let left = v.clone().and_then(&f).and_then(&g);
+    let right = v.and_then(|a| f(a).and_then(&g));
+    prop_assert_eq!(left, right);
+    Ok(())
+}
+
+/// `v.fold(ok, err)` is `v.and_then(ok)` when `v` succeeds, and
+/// `v.and(err())` when it fails.
+pub fn fold_consistency<A, B, E, T, V>(
+    v: V,
+    ok: impl Fn(A) -> Valid<B, E, T>,
+    err: impl Fn() -> Valid<B, E, T>,
+) -> Result<(), TestCaseError>
+where
+    B: PartialEq + Debug,
+    E: Clone + PartialEq + Debug,
+    T: Clone + PartialEq + Debug,
+    V: Validator<A, E, T> + Clone,
+{
+    let mut has_value = false;
+    let valid = v.clone().to_valid().map(|a| {
+        has_value = true;
+        a
+    });
+    let expected = match has_value {
+        true => valid.and_then(&ok),
+        false => valid.and(err()),
+    };
+    prop_assert_eq!(v.fold(ok, err), expected);
+    Ok(())
+}
+
+/// Runs every law against validators generated by `validators`, using
+/// validators of the same strategy as the functions passed to `and_then` and
+/// `fold`.
+///
+/// # Panics
+/// With the smallest failing input, if a law doesn't hold.
+///
+/// # Examples
+/// ```
+/// use proptest::prelude::*;
+/// use tailcall_valid::proptest::laws::check_laws;
+/// use tailcall_valid::Valid;
+///
+/// check_laws(any::<Valid<u8, String, u8>>());
+/// ```
+pub fn check_laws<A, E, T, V>(validators: impl Strategy<Value = V>)
+where
+    A: Clone + PartialEq + Debug,
+    E: Clone + PartialEq + Debug,
+    T: Arbitrary + Clone + PartialEq + Debug,
+    V: Validator<A, E, T> + Clone + Debug,
+{
+    let strategy = (vec(validators, 3), any::<T>());
+    let result = TestRunner::default().run(&strategy, |(validators, trace)| {
+        let [a, b, c]: [V; 3] = validators.try_into().unwrap();
+        let constant = |v: &V| {
+            let v = v.clone().to_valid();
+            move |_| v.clone()
+        };
+
+        map_identity(a.clone())?;
+        zip_identity(a.clone())?;
+        zip_associativity(a.clone(), b.clone(), c.clone())?;
+        zip_error_order(a.clone(), b.clone())?;
+        trace_distributes_over_zip(a.clone(), b.clone(), trace)?;
+        and_fuse_match_zip(a.clone(), b.clone())?;
+        if let Ok(value) = a.clone().to_result() {
+            and_then_left_identity(value, constant(&b))?;
+        }
+        and_then_right_identity(a.clone())?;
+        and_then_associativity(a.clone(), constant(&b), constant(&c))?;
+        let err = c.to_valid();
+        fold_consistency(a, constant(&b), move || err.clone())?;
+        Ok(())
+    });
+    if let Err(error) = result {
+        panic!("{}", error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::check_laws;
+    use crate::{Cause, Valid, Validator};
+
+    #[test]
+    fn test_valid_obeys_laws() {
+        check_laws(any::<Valid<u8, u8, u8>>());
+        check_laws(any::<Valid<(), String, String>>());
+    }
+
+    /// A validator that forgets to trace its diagnostics.
+    #[derive(Clone, Debug)]
+    struct Untraced(Valid<u8, u8, u8>);
+
+    impl Validator<u8, u8, u8> for Untraced {
+        fn to_result(self) -> Result<u8, Vec<Cause<u8, u8>>> {
+            self.0.to_result()
+        }
+
+        fn to_valid(self) -> Valid<u8, u8, u8> {
+            self.0
+        }
+
+        fn is_succeed(&self) -> bool {
+            self.0.is_succeed()
+        }
+
+        fn is_fail(&self) -> bool {
+            self.0.is_fail()
+        }
+
+        fn trace(self, trace: impl Into<u8> + Clone) -> Valid<u8, u8, u8> {
+            let Valid(value, causes) = self.0;
+            let causes = causes
+                .into_iter()
+                .map(|cause| match cause.severity.is_error() {
+                    true => cause.trace(trace.clone().into()),
+                    false => cause,
+                })
+                .collect();
+            Valid(value, causes)
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Test failed")]
+    fn test_broken_validator() {
+        check_laws(any::<Valid<u8, u8, u8>>().prop_map(Untraced));
+    }
+}
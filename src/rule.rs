@@ -0,0 +1,266 @@
+//! Built-in validation rules.
+//!
+//! These are the checks used by `#[derive(Validate)]`, but they are plain
+//! functions and can be called directly as well. Every rule succeeds with `()`
+//! or fails with a single human readable message converted into `E`.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+pub use regex::Regex;
+
+use crate::Valid;
+
+/// Types that have a length that can be checked with [`length`].
+pub trait Length {
+    fn length(&self) -> usize;
+}
+
+impl Length for str {
+    /// The number of characters, not bytes.
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl Length for String {
+    fn length(&self) -> usize {
+        self.as_str().length()
+    }
+}
+
+impl<A> Length for [A] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<A> Length for Vec<A> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<A> Length for VecDeque<A> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V, S> Length for HashMap<K, V, S> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> Length for BTreeMap<K, V> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<A, S> Length for HashSet<A, S> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<A> Length for BTreeSet<A> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<L: Length + ?Sized> Length for &L {
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+/// Checks that the length of a value is within the given bounds (inclusive).
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{rule, Valid, Validator};
+/// let result: Valid<(), String, ()> = rule::length("hello", Some(1), Some(3));
+/// assert_eq!(result, Valid::fail("length must be at most 3, found 5".to_string()));
+/// ```
+pub fn length<E: From<String>, T>(
+    value: &(impl Length + ?Sized),
+    min: Option<usize>,
+    max: Option<usize>,
+) -> Valid<(), E, T> {
+    let length = value.length();
+    match (min, max) {
+        (Some(min), _) if length < min => Valid::fail(E::from(format!(
+            "length must be at least {}, found {}",
+            min, length
+        ))),
+        (_, Some(max)) if length > max => Valid::fail(E::from(format!(
+            "length must be at most {}, found {}",
+            max, length
+        ))),
+        _ => Valid::succeed(()),
+    }
+}
+
+/// Checks that a value is within the given bounds (inclusive).
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{rule, Valid, Validator};
+/// let result: Valid<(), String, ()> = rule::range(&-1, Some(0), None);
+/// assert_eq!(result, Valid::fail("must be at least 0, found -1".to_string()));
+/// ```
+pub fn range<V: PartialOrd + Display, E: From<String>, T>(
+    value: &V,
+    min: Option<V>,
+    max: Option<V>,
+) -> Valid<(), E, T> {
+    match (min, max) {
+        (Some(min), _) if *value < min => Valid::fail(E::from(format!(
+            "must be at least {}, found {}",
+            min, value
+        ))),
+        (_, Some(max)) if *value > max => {
+            Valid::fail(E::from(format!("must be at most {}, found {}", max, value)))
+        }
+        _ => Valid::succeed(()),
+    }
+}
+
+/// Checks that a string matches the given regular expression.
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{rule, Valid, Validator};
+/// let regex = rule::Regex::new("^[a-z]+$").unwrap();
+/// let result: Valid<(), String, ()> = rule::pattern("Hello", &regex);
+/// assert!(result.is_fail());
+/// ```
+pub fn pattern<E: From<String>, T>(
+    value: &(impl AsRef<str> + ?Sized),
+    regex: &Regex,
+) -> Valid<(), E, T> {
+    if regex.is_match(value.as_ref()) {
+        Valid::succeed(())
+    } else {
+        Valid::fail(E::from(format!(
+            "does not match pattern `{}`",
+            regex.as_str()
+        )))
+    }
+}
+
+/// Checks that a string looks like an email address.
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{rule, Valid, Validator};
+/// let result: Valid<(), String, ()> = rule::email("user@example.com");
+/// assert!(result.is_succeed());
+/// ```
+pub fn email<E: From<String>, T>(value: &(impl AsRef<str> + ?Sized)) -> Valid<(), E, T> {
+    static EMAIL: OnceLock<Regex> = OnceLock::new();
+    let regex = EMAIL.get_or_init(|| Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").unwrap());
+    if regex.is_match(value.as_ref()) {
+        Valid::succeed(())
+    } else {
+        Valid::fail(E::from("is not a valid email address".to_string()))
+    }
+}
+
+/// Checks that a string is an absolute URL with a scheme and a host.
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{rule, Valid, Validator};
+/// let result: Valid<(), String, ()> = rule::url("/relative/path");
+/// assert!(result.is_fail());
+/// ```
+pub fn url<E: From<String>, T>(value: &(impl AsRef<str> + ?Sized)) -> Valid<(), E, T> {
+    match value.as_ref().parse::<http::Uri>() {
+        Ok(uri) if uri.scheme().is_some() && uri.host().is_some() => Valid::succeed(()),
+        _ => Valid::fail(E::from("is not a valid url".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::Regex;
+    use crate::{Valid, Validator};
+
+    #[test]
+    fn test_length() {
+        let result: Valid<(), String, ()> = super::length("abc", Some(1), Some(3));
+        assert_eq!(result, Valid::succeed(()));
+
+        let result: Valid<(), String, ()> = super::length("", Some(1), None);
+        assert_eq!(
+            result,
+            Valid::fail("length must be at least 1, found 0".to_string())
+        );
+
+        let result: Valid<(), String, ()> = super::length(&vec![1, 2, 3], None, Some(2));
+        assert_eq!(
+            result,
+            Valid::fail("length must be at most 2, found 3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_length_counts_chars() {
+        let result: Valid<(), String, ()> = super::length("héllo", None, Some(5));
+        assert!(result.is_succeed());
+    }
+
+    #[test]
+    fn test_range() {
+        let result: Valid<(), String, ()> = super::range(&5, Some(0), Some(10));
+        assert!(result.is_succeed());
+
+        let result: Valid<(), String, ()> = super::range(&1.5, None, Some(1.0));
+        assert_eq!(
+            result,
+            Valid::fail("must be at most 1, found 1.5".to_string())
+        );
+    }
+
+    #[test]
+    fn test_pattern() {
+        let regex = Regex::new("^[a-z]+$").unwrap();
+        let result: Valid<(), String, ()> = super::pattern("abc", &regex);
+        assert!(result.is_succeed());
+
+        let result: Valid<(), String, ()> = super::pattern("ABC", &regex);
+        assert_eq!(
+            result,
+            Valid::fail("does not match pattern `^[a-z]+$`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_email() {
+        let valid = |value: &str| -> bool {
+            let result: Valid<(), String, ()> = super::email(value);
+            result.is_succeed()
+        };
+        assert!(valid("user@example.com"));
+        assert!(!valid("user@localhost"));
+        assert!(!valid("not an email"));
+    }
+
+    #[test]
+    fn test_url() {
+        let valid = |value: &str| -> bool {
+            let result: Valid<(), String, ()> = super::url(value);
+            result.is_succeed()
+        };
+        assert!(valid("https://example.com/path?q=1"));
+        assert!(!valid("example.com"));
+        assert!(!valid("not a url"));
+    }
+}
@@ -0,0 +1,469 @@
+//! Assertions for tests of validators.
+//!
+//! [`assert_valid!`](crate::assert_valid) and
+//! [`assert_fails!`](crate::assert_fails) print every cause when they fail,
+//! and causes are matched with [`Matcher`]s rather than compared as whole
+//! structs, so tests only specify what they care about.
+//!
+//! # Examples
+//! ```
+//! use tailcall_valid::testing::Matcher;
+//! use tailcall_valid::{assert_fails, assert_valid, Valid, Validator};
+//!
+//! assert_eq!(assert_valid!(Valid::<_, &str, &str>::succeed(1)), 1);
+//!
+//! let valid = Valid::<(), &str, &str>::fail("must be a string")
+//!     .trace("email")
+//!     .zip(Valid::<(), _, _>::fail("is required").trace("name"))
+//!     .trace("user");
+//! assert_fails!(valid, unordered [
+//!     Matcher::error("is required").at(["user", "name"]),
+//!     Matcher::contains("string"),
+//! ]);
+//! ```
+//!
+//! [`Snapshots`] compares the causes of a validator for every input of a
+//! directory with stored snapshots.
+
+mod snapshot;
+
+use std::fmt::{Display, Write};
+
+use regex::Regex;
+pub use snapshot::*;
+
+use crate::{Cause, Severity, Valid, Validator};
+
+/// How the error of a cause is matched.
+#[derive(Clone, Debug)]
+pub enum ErrorMatch {
+    Exact(String),
+    Contains(String),
+    Regex(Regex),
+}
+
+impl ErrorMatch {
+    fn matches(&self, error: &str) -> bool {
+        match self {
+            ErrorMatch::Exact(expected) => error == expected,
+            ErrorMatch::Contains(expected) => error.contains(expected.as_str()),
+            ErrorMatch::Regex(regex) => regex.is_match(error),
+        }
+    }
+}
+
+impl Display for ErrorMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorMatch::Exact(expected) => write!(f, "{:?}", expected),
+            ErrorMatch::Contains(expected) => write!(f, "containing {:?}", expected),
+            ErrorMatch::Regex(regex) => write!(f, "matching /{}/", regex),
+        }
+    }
+}
+
+/// Describes the causes an assertion expects.
+///
+/// Errors and trace segments are compared by their `Display` output. Anything
+/// that isn't specified matches every cause.
+#[derive(Clone, Debug, Default)]
+pub struct Matcher {
+    error: Option<ErrorMatch>,
+    trace: Option<Vec<String>>,
+    severity: Option<Severity>,
+}
+
+impl Matcher {
+    /// Matches every cause.
+    pub fn any() -> Self {
+        Matcher::default()
+    }
+
+    /// Matches causes whose error is exactly `error`.
+    pub fn error(error: impl Display) -> Self {
+        Matcher {
+            error: Some(ErrorMatch::Exact(error.to_string())),
+            ..Matcher::default()
+        }
+    }
+
+    /// Matches causes whose error contains `text`.
+    pub fn contains(text: impl Display) -> Self {
+        Matcher {
+            error: Some(ErrorMatch::Contains(text.to_string())),
+            ..Matcher::default()
+        }
+    }
+
+    /// Matches causes whose error matches a regular expression.
+    ///
+    /// # Panics
+    /// If the pattern is not a valid regular expression.
+    pub fn regex(pattern: &str) -> Self {
+        let regex = Regex::new(pattern)
+            .unwrap_or_else(|error| panic!("invalid pattern {:?}: {}", pattern, error));
+        Matcher {
+            error: Some(ErrorMatch::Regex(regex)),
+            ..Matcher::default()
+        }
+    }
+
+    /// Only matches causes with exactly this trace.
+    pub fn at<S: Display>(mut self, trace: impl IntoIterator<Item = S>) -> Self {
+        self.trace = Some(trace.into_iter().map(|s| s.to_string()).collect());
+        self
+    }
+
+    /// Only matches causes with this severity.
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    pub fn matches<E: Display, T: Display>(&self, cause: &Cause<E, T>) -> bool {
+        let error = self
+            .error
+            .as_ref()
+            .is_none_or(|error| error.matches(&cause.error.to_string()));
+        let trace = self.trace.as_ref().is_none_or(|trace| {
+            trace.len() == cause.trace.len()
+                && trace
+                    .iter()
+                    .zip(&cause.trace)
+                    .all(|(a, b)| *a == b.to_string())
+        });
+        let severity = self.severity.is_none_or(|s| s == cause.severity);
+        error && trace && severity
+    }
+}
+
+impl From<&str> for Matcher {
+    /// Matches causes whose error is exactly the string.
+    fn from(error: &str) -> Self {
+        Matcher::error(error)
+    }
+}
+
+impl From<String> for Matcher {
+    fn from(error: String) -> Self {
+        Matcher::error(error)
+    }
+}
+
+impl Display for Matcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(trace) = &self.trace {
+            write!(f, "[{}] ", trace.join(", "))?;
+        }
+        if let Some(severity) = &self.severity {
+            write!(f, "{} ", severity)?;
+        }
+        match &self.error {
+            Some(error) => write!(f, "{}", error),
+            None => write!(f, "any cause"),
+        }
+    }
+}
+
+/// How [`assert_causes`] compares the causes with the matchers.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Mode {
+    /// Every cause matches the matcher at the same position.
+    Ordered,
+    /// Every cause matches a different matcher, in any order.
+    Unordered,
+    /// Every matcher matches a different cause, other causes are ignored.
+    Contains,
+}
+
+/// Pairs matchers with distinct causes, returning the index of the cause
+/// paired with each matcher.
+fn pair<E: Display, T: Display>(
+    causes: &[Cause<E, T>],
+    matchers: &[Matcher],
+) -> Vec<Option<usize>> {
+    fn augment<E: Display, T: Display>(
+        m: usize,
+        causes: &[Cause<E, T>],
+        matchers: &[Matcher],
+        owner: &mut [Option<usize>],
+        seen: &mut [bool],
+    ) -> bool {
+        for (c, cause) in causes.iter().enumerate() {
+            if seen[c] || !matchers[m].matches(cause) {
+                continue;
+            }
+            seen[c] = true;
+            let free = match owner[c] {
+                None => true,
+                Some(other) => augment(other, causes, matchers, owner, seen),
+            };
+            if free {
+                owner[c] = Some(m);
+                return true;
+            }
+        }
+        false
+    }
+
+    let mut owner = vec![None; causes.len()];
+    for m in 0..matchers.len() {
+        augment(
+            m,
+            causes,
+            matchers,
+            &mut owner,
+            &mut vec![false; causes.len()],
+        );
+    }
+    let mut paired = vec![None; matchers.len()];
+    for (c, m) in owner.into_iter().enumerate() {
+        if let Some(m) = m {
+            paired[m] = Some(c);
+        }
+    }
+    paired
+}
+
+/// Lists causes one per line, the way assertion failures show them.
+pub fn format_causes<E: Display, T: Display>(causes: &[Cause<E, T>]) -> String {
+    let mut out = String::new();
+    for cause in causes {
+        match cause.severity {
+            Severity::Error => writeln!(out, "    {}", cause).unwrap(),
+            severity => writeln!(out, "    {}: {}", severity, cause).unwrap(),
+        }
+    }
+    if causes.is_empty() {
+        out.push_str("    (none)\n");
+    }
+    out
+}
+
+/// Checks causes against matchers, returning a description of the mismatch.
+pub fn check_causes<E: Display, T: Display>(
+    causes: &[Cause<E, T>],
+    matchers: &[Matcher],
+    mode: Mode,
+) -> Result<(), String> {
+    let unmatched: Vec<&Matcher> = match mode {
+        Mode::Ordered => {
+            let mismatch = causes.len() != matchers.len()
+                || causes.iter().zip(matchers).any(|(c, m)| !m.matches(c));
+            match mismatch {
+                true => matchers.iter().collect(),
+                false => Vec::new(),
+            }
+        }
+        Mode::Unordered | Mode::Contains => {
+            let paired = pair(causes, matchers);
+            let mut unmatched: Vec<&Matcher> = matchers
+                .iter()
+                .zip(&paired)
+                .filter(|(_, c)| c.is_none())
+                .map(|(m, _)| m)
+                .collect();
+            if mode == Mode::Unordered && unmatched.is_empty() && causes.len() != matchers.len() {
+                unmatched = matchers.iter().collect();
+            }
+            unmatched
+        }
+    };
+    if unmatched.is_empty() {
+        return Ok(());
+    }
+
+    let mut message = match mode {
+        Mode::Ordered => "causes don't match, in order:\n".to_string(),
+        Mode::Unordered => "causes don't match, in any order:\n".to_string(),
+        Mode::Contains => "causes are missing:\n".to_string(),
+    };
+    for matcher in unmatched {
+        writeln!(message, "    {}", matcher).unwrap();
+    }
+    writeln!(message, "  actual causes:").unwrap();
+    message.push_str(&format_causes(causes));
+    Err(message)
+}
+
+/// Panics unless the causes match, see [`check_causes`].
+#[track_caller]
+pub fn assert_causes<E: Display, T: Display>(
+    causes: &[Cause<E, T>],
+    matchers: &[Matcher],
+    mode: Mode,
+) {
+    if let Err(message) = check_causes(causes, matchers, mode) {
+        panic!("{}", message);
+    }
+}
+
+/// Returns the value of a successful validation, or panics listing the
+/// causes.
+#[track_caller]
+pub fn unwrap_valid<A, E: Display, T: Display>(valid: Valid<A, E, T>) -> A {
+    match valid.to_result() {
+        Ok(a) => a,
+        Err(causes) => panic!(
+            "expected validation to succeed, but it failed with:\n{}",
+            format_causes(&causes)
+        ),
+    }
+}
+
+/// Returns the causes of a failed validation, or panics if it succeeded.
+#[track_caller]
+pub fn unwrap_causes<A, E, T>(valid: Valid<A, E, T>) -> Vec<Cause<E, T>> {
+    match valid.to_result() {
+        Ok(_) => panic!("expected validation to fail, but it succeeded"),
+        Err(causes) => causes,
+    }
+}
+
+/// Asserts that a validation succeeds and returns its value, or compares it
+/// with an expected value.
+///
+/// On failure, every cause is printed.
+#[macro_export]
+macro_rules! assert_valid {
+    ($valid:expr $(,)?) => {
+        $crate::testing::unwrap_valid($crate::Validator::to_valid($valid))
+    };
+    ($valid:expr, $expected:expr $(,)?) => {
+        assert_eq!(
+            $crate::testing::unwrap_valid($crate::Validator::to_valid($valid)),
+            $expected
+        )
+    };
+}
+
+/// Asserts that a validation fails and returns its causes.
+///
+/// The causes can be matched against a list of [`Matcher`]s, or anything that
+/// converts into one:
+///
+/// - `assert_fails!(valid, [..])` expects exactly these causes, in order.
+/// - `assert_fails!(valid, unordered [..])` expects exactly these causes, in
+///   any order.
+/// - `assert_fails!(valid, contains [..])` expects at least these causes.
+///
+/// [`Matcher`]: crate::testing::Matcher
+#[macro_export]
+macro_rules! assert_fails {
+    ($valid:expr $(,)?) => {
+        $crate::testing::unwrap_causes($crate::Validator::to_valid($valid))
+    };
+    ($valid:expr, [$($m:expr),* $(,)?] $(,)?) => {
+        $crate::assert_fails!(@mode Ordered, $valid, [$($m),*])
+    };
+    ($valid:expr, unordered [$($m:expr),* $(,)?] $(,)?) => {
+        $crate::assert_fails!(@mode Unordered, $valid, [$($m),*])
+    };
+    ($valid:expr, contains [$($m:expr),* $(,)?] $(,)?) => {
+        $crate::assert_fails!(@mode Contains, $valid, [$($m),*])
+    };
+    (@mode $mode:ident, $valid:expr, [$($m:expr),*]) => {{
+        let causes = $crate::testing::unwrap_causes($crate::Validator::to_valid($valid));
+        $crate::testing::assert_causes(
+            &causes,
+            &[$($crate::testing::Matcher::from($m)),*],
+            $crate::testing::Mode::$mode,
+        );
+        causes
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{check_causes, Matcher, Mode};
+    use crate::{Cause, Severity, Valid, Validator};
+
+    fn valid() -> Valid<(), String, String> {
+        Valid::<(), String, String>::fail("must be a string".to_string())
+            .trace("email")
+            .and(Valid::<(), _, _>::fail("is required".to_string()).trace("name"))
+            .trace("user")
+            .and(Valid::<(), _, _>::succeed(()).warn("is deprecated".to_string()))
+    }
+
+    #[test]
+    fn test_matcher() {
+        let cause: Cause<&str, &str> = Cause::new("must be at most 64 characters")
+            .trace("name")
+            .trace("user");
+        assert!(Matcher::any().matches(&cause));
+        assert!(Matcher::error("must be at most 64 characters").matches(&cause));
+        assert!(Matcher::contains("64").at(["user", "name"]).matches(&cause));
+        assert!(Matcher::regex(r"at most \d+").matches(&cause));
+        assert!(!Matcher::contains("64").at(["user"]).matches(&cause));
+        assert!(!Matcher::error("must be").matches(&cause));
+        assert!(!Matcher::any().severity(Severity::Warning).matches(&cause));
+    }
+
+    #[test]
+    fn test_assert_valid() {
+        let value = crate::assert_valid!(Valid::<_, String, String>::succeed(1));
+        assert_eq!(value, 1);
+        crate::assert_valid!(Valid::<_, String, String>::succeed(2), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "it failed with:\n    [user, email] must be a string\n")]
+    fn test_assert_valid_fails() {
+        crate::assert_valid!(valid());
+    }
+
+    #[test]
+    fn test_assert_fails() {
+        let causes = crate::assert_fails!(valid(), ["must be a string", "is required"]);
+        assert_eq!(causes.len(), 2);
+        crate::assert_fails!(
+            valid(),
+            unordered [
+                Matcher::error("is required").at(["user", "name"]),
+                Matcher::regex("^must"),
+            ]
+        );
+        crate::assert_fails!(valid(), contains[Matcher::contains("required")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected validation to fail, but it succeeded")]
+    fn test_assert_fails_succeeded() {
+        crate::assert_fails!(Valid::<(), String, String>::succeed(()));
+    }
+
+    #[test]
+    fn test_unordered_pairs_overlapping_matchers() {
+        // A greedy pairing would give "ab" to the first matcher, leaving
+        // nothing for the second one.
+        let causes = vec![Cause::<&str, &str>::new("ab"), Cause::new("a")];
+        let matchers = [Matcher::contains("a"), Matcher::contains("b")];
+        assert_eq!(check_causes(&causes, &matchers, Mode::Unordered), Ok(()));
+    }
+
+    #[test]
+    fn test_mismatch_message() {
+        let causes = crate::assert_fails!(valid());
+        let message = check_causes(
+            &causes,
+            &[Matcher::contains("required"), Matcher::error("is unknown")],
+            Mode::Contains,
+        )
+        .unwrap_err();
+        let expected = [
+            "causes are missing:",
+            "    \"is unknown\"",
+            "  actual causes:",
+            "    [user, email] must be a string",
+            "    [user, name] is required",
+            "",
+        ];
+        assert_eq!(message, expected.join("\n"));
+
+        let message = check_causes(&causes, &["is required".into()], Mode::Unordered).unwrap_err();
+        assert!(message.starts_with("causes don't match, in any order:\n    \"is required\"\n"));
+    }
+}
@@ -0,0 +1,340 @@
+use std::fmt::{Display, Write};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::{Valid, Validator};
+
+/// The environment variable that makes [`Snapshots`] write the snapshots
+/// instead of comparing them.
+pub const UPDATE_SNAPSHOTS: &str = "UPDATE_SNAPSHOTS";
+
+/// How a validation is rendered in a snapshot.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Format {
+    /// One `severity: [trace] error` line per cause, followed by an indented
+    /// `help:` line when the cause has one. A validation without causes is
+    /// rendered as `ok`.
+    #[default]
+    Text,
+    /// A pretty printed `{"valid": bool, "causes": [...]}` object, with the
+    /// causes serialized as by [`Cause`](crate::Cause)'s `Serialize` implementation.
+    Json,
+}
+
+/// Renders the causes of a validation, recovered errors and warnings included,
+/// in the order they were found and in a form that is stable enough to be
+/// stored in a snapshot.
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::testing::{render_report, Format};
+/// use tailcall_valid::{Valid, Validator};
+///
+/// let valid = Valid::<(), &str, &str>::fail("must be a number")
+///     .trace("port")
+///     .warn("is deprecated");
+/// assert_eq!(
+///     render_report(&valid, Format::Text),
+///     "error: [port] must be a number\nwarning: [] is deprecated\n"
+/// );
+/// ```
+pub fn render_report<A, E, T>(valid: &Valid<A, E, T>, format: Format) -> String
+where
+    E: Display + Serialize,
+    T: Display + Serialize,
+{
+    let causes = valid.causes();
+
+    match format {
+        Format::Text => {
+            let mut out = String::new();
+            for cause in causes {
+                writeln!(out, "{}: {}", cause.severity, cause).unwrap();
+                if let Some(help) = &cause.help {
+                    writeln!(out, "  help: {}", help).unwrap();
+                }
+            }
+            if causes.is_empty() {
+                out.push_str("ok\n");
+            }
+            out
+        }
+        Format::Json => {
+            let report = serde_json::json!({ "valid": valid.is_succeed(), "causes": causes });
+            let mut out = serde_json::to_string_pretty(&report).unwrap();
+            out.push('\n');
+            out
+        }
+    }
+}
+
+/// Runs a validator over every file of a directory and compares the rendered
+/// results with the `.snap` file stored next to each input.
+///
+/// Snapshots are named after their input: `bad_port.json` is compared with
+/// `bad_port.json.snap`. When the [`UPDATE_SNAPSHOTS`] environment variable
+/// is set, the snapshots are written instead, so a regression test is added by
+/// dropping an input into the directory and running the tests once with
+/// `UPDATE_SNAPSHOTS=1`.
+///
+/// # Examples
+/// ```no_run
+/// use tailcall_valid::testing::{Format, Snapshots};
+/// use tailcall_valid::{PathSegment, Valid};
+///
+/// Snapshots::new("tests/fixtures/config")
+///     .extension("json")
+///     .format(Format::Json)
+///     .run(|input| Valid::<serde_json::Value, String, PathSegment>::from_json_str(input));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Snapshots {
+    dir: PathBuf,
+    extension: Option<String>,
+    format: Format,
+    update: bool,
+}
+
+impl Snapshots {
+    /// Creates a harness for the inputs of `dir`, in update mode if
+    /// [`UPDATE_SNAPSHOTS`] is set.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Snapshots {
+            dir: dir.into(),
+            extension: None,
+            format: Format::default(),
+            update: std::env::var_os(UPDATE_SNAPSHOTS).is_some(),
+        }
+    }
+
+    /// Only uses the files with this extension as inputs.
+    pub fn extension(mut self, extension: impl Into<String>) -> Self {
+        self.extension = Some(extension.into());
+        self
+    }
+
+    /// Sets how the results are rendered. Defaults to [`Format::Text`].
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Writes the snapshots instead of comparing them, regardless of
+    /// [`UPDATE_SNAPSHOTS`].
+    pub fn update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// Returns the inputs of the directory, sorted by name.
+    fn inputs(&self) -> Result<Vec<PathBuf>, String> {
+        let entries = std::fs::read_dir(&self.dir)
+            .map_err(|error| format!("failed to read {}: {}", self.dir.display(), error))?;
+        let mut inputs = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|error| format!("failed to read {}: {}", self.dir.display(), error))?
+                .path();
+            let is_input = path.is_file()
+                && path.extension().is_some_and(|extension| {
+                    extension != "snap"
+                        && self
+                            .extension
+                            .as_ref()
+                            .is_none_or(|expected| extension == expected.as_str())
+                });
+            if is_input {
+                inputs.push(path);
+            }
+        }
+        inputs.sort();
+        match inputs.is_empty() {
+            true => Err(format!("no inputs found in {}", self.dir.display())),
+            false => Ok(inputs),
+        }
+    }
+
+    /// Validates every input and compares, or in update mode writes, its
+    /// snapshot. Returns the number of inputs, or a description of every
+    /// snapshot that didn't match.
+    pub fn check<A, E, T, V>(&self, mut validate: impl FnMut(&str) -> V) -> Result<usize, String>
+    where
+        E: Display + Serialize,
+        T: Display + Serialize,
+        V: Validator<A, E, T>,
+    {
+        let inputs = self.inputs()?;
+        let mut failures = String::new();
+        for input in &inputs {
+            let source = std::fs::read_to_string(input)
+                .map_err(|error| format!("failed to read {}: {}", input.display(), error))?;
+            let actual = render_report(&validate(&source).to_valid(), self.format);
+            let snap = snapshot_path(input);
+
+            if self.update {
+                std::fs::write(&snap, &actual)
+                    .map_err(|error| format!("failed to write {}: {}", snap.display(), error))?;
+                continue;
+            }
+
+            let name = input.file_name().unwrap_or_default().to_string_lossy();
+            match std::fs::read_to_string(&snap) {
+                Ok(expected) if expected.replace("\r\n", "\n") == actual => {}
+                Ok(expected) => write!(
+                    failures,
+                    "snapshot of {} doesn't match\n--- expected\n{}+++ actual\n{}\n",
+                    name, expected, actual
+                )
+                .unwrap(),
+                Err(_) => write!(
+                    failures,
+                    "snapshot of {} is missing\n+++ actual\n{}\n",
+                    name, actual
+                )
+                .unwrap(),
+            }
+        }
+
+        match failures.is_empty() {
+            true => Ok(inputs.len()),
+            false => Err(format!(
+                "{}run with {}=1 to update the snapshots",
+                failures, UPDATE_SNAPSHOTS
+            )),
+        }
+    }
+
+    /// Like [`Snapshots::check`], but panics if a snapshot doesn't match.
+    pub fn run<A, E, T, V>(&self, validate: impl FnMut(&str) -> V)
+    where
+        E: Display + Serialize,
+        T: Display + Serialize,
+        V: Validator<A, E, T>,
+    {
+        if let Err(message) = self.check(validate) {
+            panic!("{}", message);
+        }
+    }
+}
+
+fn snapshot_path(input: &Path) -> PathBuf {
+    let mut name = input.file_name().unwrap_or_default().to_os_string();
+    name.push(".snap");
+    input.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{render_report, Format, Snapshots};
+    use crate::{Cause, PathSegment, Valid, Validator};
+
+    fn port(input: &str) -> Valid<u16, String, PathSegment> {
+        match input.trim().parse::<u16>() {
+            Ok(port) => Valid::succeed(port),
+            Err(error) => Valid::fail(error.to_string()).trace("port"),
+        }
+    }
+
+    /// Creates an empty directory for the test, removing what an earlier
+    /// run left behind.
+    fn dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tailcall-valid-snapshot-{}", name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_render_text() {
+        let valid = Valid::<(), String, &str>::from(vec![
+            Cause::new("is required".to_string()).trace("name"),
+            Cause::new("is too long".to_string()).help("use at most 8 characters"),
+        ])
+        .info("checked".to_string());
+        assert_eq!(
+            render_report(&valid, Format::Text),
+            "error: [name] is required\nerror: [] is too long\n  help: use at most 8 characters\ninfo: [] checked\n"
+        );
+        assert_eq!(
+            render_report(&Valid::<_, String, &str>::succeed(1), Format::Text),
+            "ok\n"
+        );
+    }
+
+    #[test]
+    fn test_render_json() {
+        let valid = Valid::<u16, String, &str>::succeed(80).warn("is deprecated".to_string());
+        let expected = serde_json::json!({
+            "valid": true,
+            "causes": [{ "message": "is deprecated", "path": [], "severity": "warning" }]
+        });
+        let actual: serde_json::Value =
+            serde_json::from_str(&render_report(&valid, Format::Json)).unwrap();
+        assert_eq!(actual, expected);
+
+        let valid = Valid::<u16, String, &str>::fail("is required".to_string()).recover(|_| 0);
+        let actual: serde_json::Value =
+            serde_json::from_str(&render_report(&valid, Format::Json)).unwrap();
+        assert_eq!(actual["valid"], false);
+    }
+
+    #[test]
+    fn test_update_and_compare() {
+        let dir = dir("update");
+        std::fs::write(dir.join("ok.txt"), "8080").unwrap();
+        std::fs::write(dir.join("bad.txt"), "http").unwrap();
+        std::fs::write(dir.join("ignored.md"), "not an input").unwrap();
+
+        let snapshots = Snapshots::new(&dir).extension("txt").update(true);
+        assert_eq!(snapshots.check(port), Ok(2));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("bad.txt.snap")).unwrap(),
+            "error: [port] invalid digit found in string\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.join("ok.txt.snap")).unwrap(),
+            "ok\n"
+        );
+        assert!(!dir.join("ignored.md.snap").exists());
+
+        assert_eq!(snapshots.update(false).check(port), Ok(2));
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let dir = dir("mismatch");
+        std::fs::write(dir.join("a.txt"), "8080").unwrap();
+        std::fs::write(dir.join("a.txt.snap"), "ok\n").unwrap();
+        std::fs::write(dir.join("b.txt"), "-1").unwrap();
+        std::fs::write(dir.join("b.txt.snap"), "ok\n").unwrap();
+        std::fs::write(dir.join("c.txt"), "80").unwrap();
+
+        let error = Snapshots::new(&dir).update(false).check(port).unwrap_err();
+        let expected = [
+            "snapshot of b.txt doesn't match",
+            "--- expected",
+            "ok",
+            "+++ actual",
+            "error: [port] invalid digit found in string",
+            "",
+            "snapshot of c.txt is missing",
+            "+++ actual",
+            "ok",
+            "",
+            "run with UPDATE_SNAPSHOTS=1 to update the snapshots",
+        ];
+        assert_eq!(error, expected.join("\n"));
+    }
+
+    #[test]
+    fn test_no_inputs() {
+        let dir = dir("empty");
+        let error = Snapshots::new(&dir).check(port).unwrap_err();
+        assert!(error.starts_with("no inputs found in"));
+    }
+}
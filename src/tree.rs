@@ -0,0 +1,300 @@
+use std::fmt::Display;
+
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+
+use crate::{Cause, Severity};
+
+/// Causes grouped by the segments of their traces.
+///
+/// Every node holds the causes whose trace ends there, and one child for each
+/// distinct next segment. Segments that display the same, such as
+/// `PathSegment::Index(0)` and `PathSegment::Field("0")`, share a node.
+/// Children are kept in the order they were first seen.
+///
+/// Displays as an indented tree, prefixing diagnostics with their severity,
+/// and serializes as `{"causes": [...], "children": {"segment": {...}}}`, with
+/// the causes serialized as by [`Cause`]'s `Serialize` implementation and
+/// leaving out empty members.
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{CauseTree, Valid, Validator};
+///
+/// let valid = Valid::<(), &str, &str>::fail("must be a string")
+///     .trace("email")
+///     .zip(Valid::<(), _, _>::fail("is required").trace("name"))
+///     .warn("is deprecated")
+///     .trace("User");
+///
+/// let tree = CauseTree::new(valid.causes().to_vec());
+/// assert_eq!(
+///     tree.to_string(),
+///     "User
+/// ├── warning: is deprecated
+/// ├── email
+/// │   └── must be a string
+/// └── name
+///     └── is required
+/// "
+/// );
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct CauseTree<E, T> {
+    pub causes: Vec<Cause<E, T>>,
+    pub children: Vec<(T, CauseTree<E, T>)>,
+}
+
+impl<E, T> Default for CauseTree<E, T> {
+    fn default() -> Self {
+        CauseTree {
+            causes: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+}
+
+impl<E, T: Clone + Display> CauseTree<E, T> {
+    pub fn new(causes: impl IntoIterator<Item = Cause<E, T>>) -> Self {
+        let mut tree = CauseTree::default();
+        for cause in causes {
+            tree.insert(cause);
+        }
+        tree
+    }
+
+    /// Adds a cause at the node its trace points to.
+    pub fn insert(&mut self, cause: Cause<E, T>) {
+        let mut node = self;
+        for segment in &cause.trace {
+            let text = segment.to_string();
+            let index = match node
+                .children
+                .iter()
+                .position(|(s, _)| s.to_string() == text)
+            {
+                Some(index) => index,
+                None => {
+                    node.children.push((segment.clone(), CauseTree::default()));
+                    node.children.len() - 1
+                }
+            };
+            node = &mut node.children[index].1;
+        }
+        node.causes.push(cause);
+    }
+}
+
+impl<E, T> CauseTree<E, T> {
+    /// Returns true if the tree holds no causes.
+    pub fn is_empty(&self) -> bool {
+        self.causes.is_empty() && self.children.is_empty()
+    }
+}
+
+impl<E, T: Clone + Display> From<Vec<Cause<E, T>>> for CauseTree<E, T> {
+    fn from(causes: Vec<Cause<E, T>>) -> Self {
+        CauseTree::new(causes)
+    }
+}
+
+/// Displays the error of a cause, prefixed with its severity unless it is an
+/// error.
+struct Label<'a, E, T>(&'a Cause<E, T>);
+
+impl<E: Display, T> Display for Label<'_, E, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0.severity {
+            Severity::Error => write!(f, "{}", self.0.error),
+            severity => write!(f, "{}: {}", severity, self.0.error),
+        }
+    }
+}
+
+impl<E: Display, T: Display> CauseTree<E, T> {
+    /// Writes the causes and children of the node, each line starting with
+    /// `prefix`.
+    fn fmt_node(&self, f: &mut std::fmt::Formatter<'_>, prefix: &str) -> std::fmt::Result {
+        let causes = self
+            .causes
+            .iter()
+            .map(|cause| (Label(cause).to_string(), None));
+        let children = self
+            .children
+            .iter()
+            .map(|(segment, child)| (segment.to_string(), Some(child)));
+        let lines: Vec<_> = causes.chain(children).collect();
+        for (i, (label, child)) in lines.iter().enumerate() {
+            let (branch, indent) = match i + 1 == lines.len() {
+                true => ("└── ", "    "),
+                false => ("├── ", "│   "),
+            };
+            writeln!(f, "{}{}{}", prefix, branch, label)?;
+            if let Some(child) = child {
+                child.fmt_node(f, &format!("{}{}", prefix, indent))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<E: Display, T: Display> Display for CauseTree<E, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for cause in &self.causes {
+            writeln!(f, "{}", Label(cause))?;
+        }
+        for (segment, child) in &self.children {
+            writeln!(f, "{}", segment)?;
+            child.fmt_node(f, "")?;
+        }
+        Ok(())
+    }
+}
+
+/// Serializes children as a map keyed by the displayed segment.
+struct Children<'a, E, T>(&'a [(T, CauseTree<E, T>)]);
+
+impl<E: Serialize, T: Display + Serialize> Serialize for Children<'_, E, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.0
+                .iter()
+                .map(|(segment, child)| (segment.to_string(), child)),
+        )
+    }
+}
+
+impl<E: Serialize, T: Display + Serialize> Serialize for CauseTree<E, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        if !self.causes.is_empty() {
+            map.serialize_entry("causes", &self.causes)?;
+        }
+        if !self.children.is_empty() {
+            map.serialize_entry("children", &Children(&self.children))?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::CauseTree;
+    use crate::{Cause, PathSegment, Severity};
+
+    fn causes() -> Vec<Cause<&'static str, PathSegment>> {
+        let at = |path: Vec<PathSegment>, error| {
+            path.into_iter()
+                .rev()
+                .fold(Cause::new(error), |cause, segment| cause.trace(segment))
+        };
+        vec![
+            at(
+                vec!["types".into(), PathSegment::key("User"), "email".into()],
+                "must be a string",
+            ),
+            at(vec!["schema".into()], "query is missing"),
+            at(
+                vec!["types".into(), PathSegment::key("User"), "email".into()],
+                "is required",
+            ),
+            at(vec![], "invalid config"),
+            at(
+                vec![
+                    "types".into(),
+                    PathSegment::key("Post"),
+                    "tags".into(),
+                    0.into(),
+                ],
+                "is empty",
+            ),
+            at(
+                vec!["types".into(), PathSegment::key("User")],
+                "has no fields",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_display() {
+        let expected = [
+            "invalid config",
+            "types",
+            "├── User",
+            "│   ├── has no fields",
+            "│   └── email",
+            "│       ├── must be a string",
+            "│       └── is required",
+            "└── Post",
+            "    └── tags",
+            "        └── 0",
+            "            └── is empty",
+            "schema",
+            "└── query is missing",
+            "",
+        ];
+        assert_eq!(CauseTree::new(causes()).to_string(), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_serialize() {
+        let causes = vec![
+            Cause::new("must be a string")
+                .trace(PathSegment::from("email"))
+                .trace(PathSegment::key("User")),
+            Cause::new("is deprecated")
+                .severity(Severity::Warning)
+                .trace(PathSegment::key("User")),
+        ];
+        let actual = serde_json::to_value(CauseTree::new(causes)).unwrap();
+        let expected = json!({
+            "children": {
+                "User": {
+                    "causes": [{ "message": "is deprecated", "path": [{ "key": "User" }], "severity": "warning" }],
+                    "children": {
+                        "email": {
+                            "causes": [{ "message": "must be a string", "path": [{ "key": "User" }, "email"] }]
+                        }
+                    }
+                }
+            }
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_same_text_segments() {
+        let causes = vec![
+            Cause::new("is empty").trace(PathSegment::Index(0)),
+            Cause::new("is unknown").trace(PathSegment::from("0")),
+            Cause::new("is required").trace(PathSegment::key("a")),
+            Cause::new("is invalid").trace(PathSegment::from("a")),
+        ];
+        let tree = CauseTree::new(causes);
+        assert_eq!(tree.children.len(), 2);
+        assert_eq!(tree.children[0].0, PathSegment::Index(0));
+        assert_eq!(tree.children[0].1.causes.len(), 2);
+        assert_eq!(tree.children[1].1.causes.len(), 2);
+
+        let actual = serde_json::to_value(&tree).unwrap();
+        assert_eq!(
+            actual["children"]["0"]["causes"][1]["message"],
+            "is unknown"
+        );
+        assert_eq!(
+            actual["children"]["a"]["causes"][1]["message"],
+            "is invalid"
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let tree: CauseTree<&str, PathSegment> = CauseTree::new(vec![]);
+        assert!(tree.is_empty());
+        assert_eq!(tree.to_string(), "");
+        assert_eq!(serde_json::to_value(&tree).unwrap(), json!({}));
+    }
+}
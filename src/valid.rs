@@ -1,13 +1,22 @@
+use std::num::NonZeroUsize;
+
 use super::append::Append;
-use super::Cause;
+use super::{Cause, PathSegment, Severity, Truncated};
 
 /// A validation type that can represent either a successful value of type `A`
 /// or a collection of validation errors of type `E` with trace context `T`.
 ///
 /// `Valid` is useful for accumulating multiple validation errors rather than
 /// stopping at the first error encountered.
+///
+/// A `Valid` holds every cause found so far, in the order they were found,
+/// along with the value when there is one. Besides errors, the causes include
+/// non-fatal diagnostics such as warnings and notes, which never make a
+/// validation fail, see [`Valid::warn`] and [`Valid::warnings`]. A value along
+/// with errors means that the validation recovered from them with
+/// [`Valid::recover`] and still fails.
 #[derive(Debug, PartialEq)]
-pub struct Valid<A, E, T>(Result<A, Vec<Cause<E, T>>>);
+pub struct Valid<A, E, T>(pub(crate) Option<A>, pub(crate) Vec<Cause<E, T>>);
 
 /// Trait for types that can perform validation operations.
 ///
@@ -24,7 +33,8 @@ pub trait Validator<A, E, T>: Sized {
     /// assert_eq!(result, Valid::succeed("1".to_string()));
     /// ```
     fn map<A1>(self, f: impl FnOnce(A) -> A1) -> Valid<A1, E, T> {
-        Valid(self.to_result().map(f))
+        let Valid(value, causes) = self.to_valid();
+        Valid(value.map(f), causes)
     }
 
     /// Executes a side effect function if the validation is successful.
@@ -42,13 +52,11 @@ pub trait Validator<A, E, T>: Sized {
     where
         A: Clone,
     {
-        match self.to_result() {
-            Ok(a) => {
-                f(a.clone());
-                Valid::succeed(a)
-            }
-            Err(e) => Valid(Err(e)),
+        let Valid(value, causes) = self.to_valid();
+        if let Some(a) = &value {
+            f(a.clone());
         }
+        Valid(value, causes)
     }
 
     /// Returns true if the validation is successful.
@@ -82,19 +90,9 @@ pub trait Validator<A, E, T>: Sized {
     /// assert_eq!(v1.zip(v2), Valid::succeed((1, "ok")));
     /// ```
     fn zip<A1>(self, other: Valid<A1, E, T>) -> Valid<(A, A1), E, T> {
-        match self.to_result() {
-            Ok(a) => match other.0 {
-                Ok(a1) => Valid(Ok((a, a1))),
-                Err(e1) => Valid(Err(e1)),
-            },
-            Err(mut e1) => match other.0 {
-                Ok(_) => Valid(Err(e1)),
-                Err(e2) => {
-                    e1.extend(e2);
-                    Valid(Err(e1))
-                }
-            },
-        }
+        let Valid(value, mut causes) = self.to_valid();
+        causes.extend(other.1);
+        Valid(value.zip(other.0), causes)
     }
 
     /// Starts a fusion chain of validations. This allows combining multiple
@@ -123,15 +121,12 @@ pub trait Validator<A, E, T>: Sized {
     ///     .trace("form");
     /// ```
     fn trace(self, trace: impl Into<T> + Clone) -> Valid<A, E, T> {
-        let valid = self.to_result();
-        if let Err(error) = valid {
-            return Valid(Err(error
-                .into_iter()
-                .map(|cause| cause.trace(trace.clone().into()))
-                .collect()));
-        }
-
-        Valid(valid)
+        let Valid(value, causes) = self.to_valid();
+        let causes = causes
+            .into_iter()
+            .map(|cause| cause.trace(trace.clone().into()))
+            .collect();
+        Valid(value, causes)
     }
 
     /// Handles both success and failure cases of a validation.
@@ -154,15 +149,24 @@ pub trait Validator<A, E, T>: Sized {
         ok: impl FnOnce(A) -> Valid<A1, E, T>,
         err: impl FnOnce() -> Valid<A1, E, T>,
     ) -> Valid<A1, E, T> {
-        match self.to_result() {
-            Ok(a) => ok(a),
-            Err(e) => Valid::<A1, E, T>(Err(e)).and(err()),
+        let Valid(value, causes) = self.to_valid();
+        match value {
+            Some(a) => ok(a).with_causes_before(causes),
+            None => Valid::<A1, E, T>(None, causes).and(err()),
         }
     }
 
-    /// Converts the validation into a Result.
+    /// Converts the validation into a Result, failing with every error in the
+    /// order they were found. Warnings and other non-fatal diagnostics are
+    /// discarded.
     fn to_result(self) -> Result<A, Vec<Cause<E, T>>>;
 
+    /// Converts the validation into a `Valid`, keeping its non-fatal
+    /// diagnostics.
+    fn to_valid(self) -> Valid<A, E, T> {
+        Valid::from(self.to_result())
+    }
+
     /// Chains a validation operation by applying a function to a successful value.
     /// If the original validation failed, the errors are propagated.
     ///
@@ -180,9 +184,10 @@ pub trait Validator<A, E, T>: Sized {
     /// assert_eq!(result, Valid::succeed(2));
     /// ```
     fn and_then<B>(self, f: impl FnOnce(A) -> Valid<B, E, T>) -> Valid<B, E, T> {
-        match self.to_result() {
-            Ok(a) => f(a),
-            Err(e) => Valid(Err(e)),
+        let Valid(value, causes) = self.to_valid();
+        match value {
+            Some(a) => f(a).with_causes_before(causes),
+            None => Valid(None, causes),
         }
     }
 
@@ -252,10 +257,7 @@ impl<A, E, T> Valid<A, E, T> {
     /// assert!(result.is_fail());
     /// ```
     pub fn fail(e: E) -> Valid<A, E, T> {
-        Valid(Err(vec![Cause {
-            error: e,
-            trace: Default::default(),
-        }]))
+        Valid(None, vec![Cause::new(e)])
     }
 
     /// Creates a new failed validation with an error and trace context.
@@ -271,7 +273,7 @@ impl<A, E, T> Valid<A, E, T> {
         E: std::fmt::Debug,
     {
         let cause = Cause::new(error).trace(trace);
-        Valid(Err(vec![cause]))
+        Valid(None, vec![cause])
     }
 
     /// Creates a new successful validation containing the given value.
@@ -283,7 +285,7 @@ impl<A, E, T> Valid<A, E, T> {
     /// assert!(result.is_succeed());
     /// ```
     pub fn succeed(a: A) -> Valid<A, E, T> {
-        Valid(Ok(a))
+        Valid(Some(a), Vec::new())
     }
 
     /// Validates each item in an iterator using the provided validation function,
@@ -303,22 +305,185 @@ impl<A, E, T> Valid<A, E, T> {
     /// ```
     pub fn from_iter<B>(
         iter: impl IntoIterator<Item = A>,
-        mut f: impl FnMut(A) -> Valid<B, E, T>,
+        f: impl FnMut(A) -> Valid<B, E, T>,
     ) -> Valid<Vec<B>, E, T> {
-        let mut values: Vec<B> = Vec::new();
-        let mut errors: Vec<Cause<E, T>> = Vec::new();
-        for a in iter.into_iter() {
-            match f(a).to_result() {
-                Ok(b) => values.push(b),
-                Err(err) => errors.extend(err),
-            }
+        iter.into_iter().map(f).collect()
+    }
+
+    /// Like [`Valid::from_iter`], but traces the errors of each item with its
+    /// position in the iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{PathSegment, Valid, Validator};
+    /// let result = Valid::from_iter_indexed(vec![1, -2, 3], |n| {
+    ///     if n > 0 {
+    ///         Valid::<i32, &str, PathSegment>::succeed(n)
+    ///     } else {
+    ///         Valid::fail("must be positive")
+    ///     }
+    /// });
+    /// let causes = result.to_result().unwrap_err();
+    /// assert_eq!(causes[0].to_string(), "[1] must be positive");
+    /// ```
+    pub fn from_iter_indexed<B>(
+        iter: impl IntoIterator<Item = A>,
+        mut f: impl FnMut(A) -> Valid<B, E, T>,
+    ) -> Valid<Vec<B>, E, T>
+    where
+        T: From<PathSegment>,
+    {
+        Valid::from_iter(iter.into_iter().enumerate(), |(index, a)| {
+            f(a).trace(PathSegment::Index(index))
+        })
+    }
+
+    /// Validates each value of a map, tracing its errors with the key of the
+    /// entry, and rebuilds a map of the same kind on success.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use tailcall_valid::{PathSegment, Valid, Validator};
+    ///
+    /// let upstreams = BTreeMap::from([("a", 10), ("b", 0)]);
+    /// let result: Valid<BTreeMap<_, _>, &str, PathSegment> =
+    ///     Valid::from_map(upstreams, |_, timeout| match timeout {
+    ///         0 => Valid::fail("timeout must not be zero"),
+    ///         n => Valid::succeed(n * 1000),
+    ///     });
+    /// let causes = result.to_result().unwrap_err();
+    /// assert_eq!(causes[0].to_string(), "[b] timeout must not be zero");
+    /// ```
+    pub fn from_map<K, V, B>(
+        map: impl IntoIterator<Item = (K, V)>,
+        mut f: impl FnMut(&K, V) -> Valid<B, E, T>,
+    ) -> Valid<A, E, T>
+    where
+        A: FromIterator<(K, B)>,
+        K: std::fmt::Display,
+        T: From<PathSegment>,
+    {
+        map.into_iter()
+            .map(|(k, v)| {
+                let key = PathSegment::key(&k);
+                f(&k, v).trace(key).map(|b| (k, b))
+            })
+            .collect()
+    }
+
+    /// Like [`Valid::from_map`], but validates the keys as well. Errors of the
+    /// key and of the value are both traced with the key of the entry.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use tailcall_valid::{PathSegment, Valid, Validator};
+    ///
+    /// let headers = vec![("x-api-key", "secret"), ("X Bad", "")];
+    /// let result: Valid<HashMap<String, String>, &str, PathSegment> = Valid::from_entries(
+    ///     headers,
+    ///     |name| match name.contains(' ') {
+    ///         true => Valid::fail("must not contain spaces"),
+    ///         false => Valid::succeed(name.to_lowercase()),
+    ///     },
+    ///     |_, value| match value.is_empty() {
+    ///         true => Valid::fail("must not be empty"),
+    ///         false => Valid::succeed(value.to_string()),
+    ///     },
+    /// );
+    /// let causes = result.to_result().unwrap_err();
+    /// assert_eq!(causes[0].to_string(), "[X Bad] must not contain spaces");
+    /// assert_eq!(causes[1].to_string(), "[X Bad] must not be empty");
+    /// ```
+    pub fn from_entries<K, V, K1, B>(
+        entries: impl IntoIterator<Item = (K, V)>,
+        mut key: impl FnMut(&K) -> Valid<K1, E, T>,
+        mut value: impl FnMut(&K, V) -> Valid<B, E, T>,
+    ) -> Valid<A, E, T>
+    where
+        A: FromIterator<(K1, B)>,
+        K: std::fmt::Display,
+        T: From<PathSegment>,
+    {
+        entries
+            .into_iter()
+            .map(|(k, v)| {
+                let segment = PathSegment::key(&k);
+                key(&k).zip(value(&k, v)).trace(segment)
+            })
+            .collect()
+    }
+
+    /// Like [`Valid::from_iter`], but stops validating once `limit` errors have
+    /// been collected. A `limit` of `None` means no limit.
+    ///
+    /// At most `limit` errors are kept. If errors past the limit were dropped
+    /// or items were left unchecked, a [`Truncated`] cause is appended so that
+    /// the output is known to be incomplete. The iterator is never consumed
+    /// past the first unchecked item, so it may be infinite.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use tailcall_valid::{Valid, Validator};
+    /// let result = Valid::from_iter_limit(1.., NonZeroUsize::new(2), |n| {
+    ///     Valid::<i32, String, ()>::fail(format!("{} is invalid", n))
+    /// });
+    /// let causes = result.to_result().unwrap_err();
+    /// assert_eq!(causes.len(), 3);
+    /// assert_eq!(
+    ///     causes[2].error,
+    ///     "stopped after 2 errors, the remaining items were not validated"
+    /// );
+    /// ```
+    pub fn from_iter_limit<B>(
+        iter: impl IntoIterator<Item = A>,
+        limit: Option<NonZeroUsize>,
+        mut f: impl FnMut(A) -> Valid<B, E, T>,
+    ) -> Valid<Vec<B>, E, T>
+    where
+        E: From<Truncated>,
+    {
+        let Some(limit) = limit.map(NonZeroUsize::get) else {
+            return Valid::from_iter(iter, f);
+        };
+
+        let mut valid = Valid::succeed(Vec::new());
+        let mut errors = 0;
+        let mut iter = iter.into_iter();
+        while errors < limit {
+            let Some(a) = iter.next() else { break };
+            let item = f(a);
+            errors += item
+                .1
+                .iter()
+                .filter(|cause| cause.severity.is_error())
+                .count();
+            valid.extend([item]);
         }
 
-        if errors.is_empty() {
-            Valid::succeed(values)
-        } else {
-            Valid::from(errors)
+        let dropped = errors.saturating_sub(limit);
+        let unvalidated = errors >= limit && iter.next().is_some();
+        if dropped > 0 || unvalidated {
+            let mut kept = 0;
+            valid.1.retain(|cause| {
+                if cause.severity.is_error() {
+                    kept += 1;
+                    kept <= limit
+                } else {
+                    true
+                }
+            });
+            let truncated = Truncated {
+                limit,
+                dropped,
+                unvalidated,
+            };
+            valid.1.push(Cause::new(E::from(truncated)));
         }
+        valid
     }
 
     /// Creates a new `Valid` from an `Option` value.
@@ -343,6 +508,138 @@ impl<A, E, T> Valid<A, E, T> {
         }
     }
 
+    /// Records a warning alongside the validation without making it fail.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    /// let valid = Valid::<i32, &str, &str>::succeed(1)
+    ///     .warn("`port` is deprecated")
+    ///     .trace("server");
+    /// assert!(valid.is_succeed());
+    /// assert_eq!(valid.warnings()[0].to_string(), "[server] `port` is deprecated");
+    /// ```
+    pub fn warn(self, e: E) -> Valid<A, E, T> {
+        self.with_diagnostic(Cause::new(e).severity(Severity::Warning))
+    }
+
+    /// Records an informational note alongside the validation without making
+    /// it fail.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Severity, Valid, Validator};
+    /// let valid = Valid::<i32, &str, ()>::succeed(1).info("using default timeout");
+    /// assert_eq!(valid.warnings()[0].severity, Severity::Info);
+    /// ```
+    pub fn info(self, e: E) -> Valid<A, E, T> {
+        self.with_diagnostic(Cause::new(e).severity(Severity::Info))
+    }
+
+    /// Records a cause according to its severity: errors make the validation
+    /// fail, warnings and notes are kept as non-fatal diagnostics.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Cause, Severity, Valid, Validator};
+    /// let valid = Valid::<i32, &str, ()>::succeed(1)
+    ///     .with_diagnostic(Cause::new("looks odd").severity(Severity::Warning));
+    /// assert!(valid.is_succeed());
+    ///
+    /// let valid = valid.with_diagnostic(Cause::new("is invalid"));
+    /// assert!(valid.is_fail());
+    /// ```
+    pub fn with_diagnostic(self, cause: Cause<E, T>) -> Valid<A, E, T> {
+        let Valid(value, mut causes) = self;
+        let value = match cause.severity {
+            Severity::Error => None,
+            Severity::Warning | Severity::Info => value,
+        };
+        causes.push(cause);
+        Valid(value, causes)
+    }
+
+    /// Continues with a fallback value when the validation failed, so that
+    /// later steps can run and report more causes.
+    ///
+    /// `f` receives every cause found so far, diagnostics included. The causes
+    /// are carried forward along with the fallback: the final result still
+    /// fails with them, in the order they were found relative to any causes
+    /// found later.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    /// let valid = Valid::<&str, &str, ()>::fail("unknown type `Strng`")
+    ///     .recover(|_| "Any")
+    ///     .and_then(|ty| match ty {
+    ///         "Any" => Valid::fail("`Any` is not allowed in input types"),
+    ///         ty => Valid::succeed(ty),
+    ///     });
+    /// let causes = valid.to_result().unwrap_err();
+    /// assert_eq!(causes[0].error, "unknown type `Strng`");
+    /// assert_eq!(causes[1].error, "`Any` is not allowed in input types");
+    /// ```
+    pub fn recover(self, f: impl FnOnce(&[Cause<E, T>]) -> A) -> Valid<A, E, T> {
+        match self {
+            Valid(None, causes) => Valid(Some(f(&causes)), causes),
+            valid => valid,
+        }
+    }
+
+    /// Returns every cause found so far, errors and diagnostics alike, in the
+    /// order they were found.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    /// let valid = Valid::<i32, &str, ()>::succeed(1)
+    ///     .warn("is deprecated")
+    ///     .and(Valid::<i32, _, _>::fail("is invalid"));
+    /// let causes: Vec<_> = valid.causes().iter().map(|c| c.error).collect();
+    /// assert_eq!(causes, vec!["is deprecated", "is invalid"]);
+    /// ```
+    pub fn causes(&self) -> &[Cause<E, T>] {
+        &self.1
+    }
+
+    /// Returns the warnings and notes found so far.
+    pub fn warnings(&self) -> Vec<&Cause<E, T>> {
+        self.1
+            .iter()
+            .filter(|cause| !cause.severity.is_error())
+            .collect()
+    }
+
+    /// Removes and returns the warnings and notes collected so far.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    /// let mut valid = Valid::<i32, &str, ()>::succeed(1)
+    ///     .warn("first")
+    ///     .zip(Valid::succeed(2).warn("second"));
+    /// let warnings = valid.drain_warnings();
+    /// assert_eq!(warnings.len(), 2);
+    /// assert_eq!(valid, Valid::succeed((1, 2)));
+    /// ```
+    pub fn drain_warnings(&mut self) -> Vec<Cause<E, T>> {
+        let (errors, warnings) = std::mem::take(&mut self.1)
+            .into_iter()
+            .partition(|cause| cause.severity.is_error());
+        self.1 = errors;
+        warnings
+    }
+
+    /// Puts `causes` in front of the ones already collected.
+    pub(crate) fn with_causes_before(mut self, mut causes: Vec<Cause<E, T>>) -> Valid<A, E, T> {
+        if !causes.is_empty() {
+            causes.append(&mut self.1);
+            self.1 = causes;
+        }
+        self
+    }
+
     /// Creates a successful validation containing `None`.
     ///
     /// This is useful when you want to explicitly represent the absence of a value
@@ -360,22 +657,39 @@ impl<A, E, T> Valid<A, E, T> {
 }
 
 impl<A, E, T> From<Cause<E, T>> for Valid<A, E, T> {
-    /// Creates a failed validation from a single `Cause`.
+    /// Creates a failed validation from a single `Cause`. A warning or a note
+    /// is kept as a diagnostic, as by [`Valid::with_diagnostic`].
+    ///
+    /// The result has no value, so it is failed even when the cause is not an
+    /// error: `to_result` then gives `Err(vec![])`, and the cause stays
+    /// available from [`Valid::warnings`].
     ///
     /// # Examples
     /// ```
-    /// use tailcall_valid::{Valid, Validator, Cause};
+    /// use tailcall_valid::{Valid, Validator, Cause, Severity};
     /// let cause = Cause::new("error");
     /// let result: Valid<(), &str, ()> = Valid::from(cause);
     /// assert!(result.is_fail());
+    ///
+    /// let warning = Cause::new("deprecated").severity(Severity::Warning);
+    /// let result: Valid<(), &str, ()> = Valid::from(warning.clone());
+    /// assert_eq!(result.warnings(), vec![&warning]);
+    /// assert_eq!(result.to_result(), Err(vec![]));
     /// ```
     fn from(value: Cause<E, T>) -> Self {
-        Valid(Err(vec![value]))
+        Valid(None, Vec::new()).with_diagnostic(value)
     }
 }
 
 impl<A, E, T> From<Vec<Cause<E, T>>> for Valid<A, E, T> {
-    /// Creates a failed validation from a vector of `Cause`s.
+    /// Creates a failed validation from a vector of `Cause`s. Warnings and
+    /// notes among them are kept as diagnostics, as by
+    /// [`Valid::with_diagnostic`].
+    ///
+    /// The result has no value, so it is failed even when none of the causes
+    /// is an error: `to_result` then gives `Err(vec![])`, and the causes stay
+    /// available from [`Valid::warnings`]. To succeed with warnings, use
+    /// [`Valid::succeed`] followed by [`Valid::with_diagnostic`].
     ///
     /// # Examples
     /// ```
@@ -385,28 +699,62 @@ impl<A, E, T> From<Vec<Cause<E, T>>> for Valid<A, E, T> {
     /// assert!(result.is_fail());
     /// ```
     fn from(value: Vec<Cause<E, T>>) -> Self {
-        Valid(Err(value))
+        value
+            .into_iter()
+            .fold(Valid(None, Vec::new()), Valid::with_diagnostic)
     }
 }
 
 impl<A, E, T> Validator<A, E, T> for Valid<A, E, T> {
     fn to_result(self) -> Result<A, Vec<Cause<E, T>>> {
-        self.0
+        let Valid(value, causes) = self;
+        let errors: Vec<Cause<E, T>> = causes
+            .into_iter()
+            .filter(|cause| cause.severity.is_error())
+            .collect();
+        match value {
+            Some(a) if errors.is_empty() => Ok(a),
+            _ => Err(errors),
+        }
+    }
+
+    fn to_valid(self) -> Valid<A, E, T> {
+        self
     }
 
     fn is_succeed(&self) -> bool {
-        self.0.is_ok()
+        self.0.is_some() && !self.1.iter().any(|cause| cause.severity.is_error())
     }
 
     fn is_fail(&self) -> bool {
-        self.0.is_err()
+        !self.is_succeed()
     }
 }
 
 /// A type that allows chaining multiple validations together while combining their results.
 ///
 /// `Fusion` is particularly useful when you want to accumulate values from multiple
-/// successful validations into a single composite value.
+/// successful validations into a single composite value. The values can be
+/// passed straight to a constructor with `map2` up to `map11`.
+///
+/// # Examples
+/// ```
+/// use tailcall_valid::{Valid, Validator};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Upstream {
+///     host: String,
+///     port: u16,
+///     timeout: u32,
+/// }
+///
+/// let upstream = Valid::<_, &str, &str>::succeed("localhost".to_string())
+///     .fuse(Valid::succeed(8080))
+///     .fuse(Valid::succeed(30))
+///     .trace("upstream")
+///     .map3(|host, port, timeout| Upstream { host, port, timeout });
+/// assert!(upstream.is_succeed());
+/// ```
 pub struct Fusion<A, E, T>(Valid<A, E, T>);
 impl<A, E, T> Fusion<A, E, T> {
     /// Combines this fusion with another validation, using the `Append` trait to
@@ -427,12 +775,48 @@ impl<A, E, T> Fusion<A, E, T> {
     {
         Fusion(self.0.zip(other).map(|(a, a1)| a.append(a1)))
     }
+
+    /// Adds trace context to any errors, like [`Validator::trace`], while
+    /// keeping the fusion going.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    /// let fusion = Valid::<i32, &str, &str>::fail("error")
+    ///     .fuse(Valid::succeed(2))
+    ///     .trace("first")
+    ///     .fuse(Valid::succeed(3));
+    /// let causes = fusion.to_result().unwrap_err();
+    /// assert_eq!(causes[0].to_string(), "[first] error");
+    /// ```
+    pub fn trace(self, trace: impl Into<T> + Clone) -> Fusion<A, E, T> {
+        Fusion(self.0.trace(trace))
+    }
+
+    /// Chains a validation on the fused values, like [`Validator::and_then`],
+    /// while keeping the fusion going.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    /// let fusion = Valid::<i32, &str, ()>::succeed(1)
+    ///     .fuse(Valid::succeed(2))
+    ///     .and_then(|(a, b)| Valid::succeed((a + b,)))
+    ///     .fuse(Valid::succeed(4));
+    /// assert_eq!(fusion.to_result(), Ok((3, 4)));
+    /// ```
+    pub fn and_then<B>(self, f: impl FnOnce(A) -> Valid<B, E, T>) -> Fusion<B, E, T> {
+        Fusion(self.0.and_then(f))
+    }
 }
 
 impl<A, E, T> Validator<A, E, T> for Fusion<A, E, T> {
     fn to_result(self) -> Result<A, Vec<Cause<E, T>>> {
         self.0.to_result()
     }
+    fn to_valid(self) -> Valid<A, E, T> {
+        self.0
+    }
     fn is_succeed(&self) -> bool {
         self.0.is_succeed()
     }
@@ -458,7 +842,7 @@ impl<A, E, T> From<Result<A, Cause<E, T>>> for Valid<A, E, T> {
     fn from(value: Result<A, Cause<E, T>>) -> Self {
         match value {
             Ok(a) => Valid::succeed(a),
-            Err(e) => Valid(Err(vec![e])),
+            Err(e) => Valid::from(e),
         }
     }
 }
@@ -483,7 +867,7 @@ impl<A, E, T> From<Result<A, Vec<Cause<E, T>>>> for Valid<A, E, T> {
     fn from(value: Result<A, Vec<Cause<E, T>>>) -> Self {
         match value {
             Ok(a) => Valid::succeed(a),
-            Err(e) => Valid(Err(e)),
+            Err(e) => Valid::from(e),
         }
     }
 }
@@ -504,7 +888,122 @@ impl<A, E, T> From<Fusion<A, E, T>> for Valid<A, E, T> {
     /// assert!(result.is_succeed());
     /// ```
     fn from(value: Fusion<A, E, T>) -> Self {
-        Valid(value.to_result())
+        value.to_valid()
+    }
+}
+
+/// Feeds the successful values of `iter` to `collect`, accumulating the causes
+/// of every item, including the ones `collect` didn't ask for.
+fn accumulate<A, B, E, T>(
+    iter: impl IntoIterator<Item = Valid<A, E, T>>,
+    collect: impl FnOnce(&mut dyn Iterator<Item = A>) -> B,
+) -> Valid<B, E, T> {
+    let mut causes: Vec<Cause<E, T>> = Vec::new();
+    let mut failed = false;
+    let mut values = iter.into_iter().filter_map(|Valid(value, item)| {
+        causes.extend(item);
+        failed |= value.is_none();
+        value
+    });
+    let b = collect(&mut values);
+    values.for_each(drop);
+
+    Valid((!failed).then_some(b), causes)
+}
+
+impl<A, C, E, T> FromIterator<Valid<A, E, T>> for Valid<C, E, T>
+where
+    C: FromIterator<A>,
+{
+    /// Collects the values of successful validations, accumulating the causes
+    /// of all the failed ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use tailcall_valid::{Valid, Validator};
+    ///
+    /// let check = |n: i32| match n {
+    ///     n if n > 0 => Valid::succeed(n),
+    ///     n => Valid::fail(format!("{} is not positive", n)),
+    /// };
+    ///
+    /// let valid: Valid<BTreeSet<i32>, String, ()> = [3, 1, 3].into_iter().map(check).collect();
+    /// assert_eq!(valid, Valid::succeed(BTreeSet::from([1, 3])));
+    ///
+    /// let valid: Valid<Vec<i32>, String, ()> = [1, -2, -3].into_iter().map(check).collect();
+    /// assert_eq!(valid.to_result().unwrap_err().len(), 2);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = Valid<A, E, T>>>(iter: I) -> Self {
+        accumulate(iter, |values| values.collect())
+    }
+}
+
+impl<A, C, E, T> Extend<Valid<A, E, T>> for Valid<C, E, T>
+where
+    C: Extend<A>,
+{
+    /// Adds the values of successful validations to the collection. Once any
+    /// validation fails, the collection is dropped and only causes are kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    ///
+    /// let mut valid = Valid::<Vec<i32>, &str, ()>::succeed(vec![1]);
+    /// valid.extend([Valid::succeed(2), Valid::succeed(3)]);
+    /// assert_eq!(valid, Valid::succeed(vec![1, 2, 3]));
+    ///
+    /// valid.extend([Valid::fail("error"), Valid::succeed(4)]);
+    /// assert!(valid.is_fail());
+    /// ```
+    fn extend<I: IntoIterator<Item = Valid<A, E, T>>>(&mut self, iter: I) {
+        for Valid(value, causes) in iter {
+            self.1.extend(causes);
+            match (&mut self.0, value) {
+                (Some(values), Some(a)) => values.extend(std::iter::once(a)),
+                (Some(_), None) => self.0 = None,
+                (None, _) => {}
+            }
+        }
+    }
+}
+
+impl<A, B, E, T> std::iter::Sum<Valid<B, E, T>> for Valid<A, E, T>
+where
+    A: std::iter::Sum<B>,
+{
+    /// Sums the values of successful validations, accumulating the causes of
+    /// all the failed ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    ///
+    /// let total: Valid<u32, &str, ()> = [1, 2, 3].into_iter().map(Valid::succeed).sum();
+    /// assert_eq!(total, Valid::succeed(6));
+    /// ```
+    fn sum<I: Iterator<Item = Valid<B, E, T>>>(iter: I) -> Self {
+        accumulate(iter, |values| values.sum())
+    }
+}
+
+impl<A, B, E, T> std::iter::Product<Valid<B, E, T>> for Valid<A, E, T>
+where
+    A: std::iter::Product<B>,
+{
+    /// Multiplies the values of successful validations, accumulating the causes
+    /// of all the failed ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use tailcall_valid::{Valid, Validator};
+    ///
+    /// let total: Valid<u32, &str, ()> = [2, 3, 4].into_iter().map(Valid::succeed).product();
+    /// assert_eq!(total, Valid::succeed(24));
+    /// ```
+    fn product<I: Iterator<Item = Valid<B, E, T>>>(iter: I) -> Self {
+        accumulate(iter, |values| values.product())
     }
 }
 
@@ -515,13 +1014,15 @@ where
     T: Clone,
 {
     fn clone(&self) -> Self {
-        Self(self.0.clone())
+        Self(self.0.clone(), self.1.clone())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Cause, Valid, Validator};
+    use std::num::NonZeroUsize;
+
+    use super::{Cause, Severity, Valid, Validator};
 
     #[test]
     fn test_ok() {
@@ -579,6 +1080,103 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_iter_indexed() {
+        let input: Vec<i32> = [1, 2, 3].to_vec();
+        let result: Valid<Vec<i32>, i32, String> =
+            Valid::from_iter_indexed(input, |a| match a % 2 {
+                0 => Valid::succeed(a),
+                _ => Valid::fail(a),
+            });
+        assert_eq!(
+            result,
+            Valid::from(vec![
+                Cause::new(1).trace("0".to_string()),
+                Cause::new(3).trace("2".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn test_from_iter_limit() {
+        let input: Vec<i32> = [1, 2, 3, 4, 5].to_vec();
+        let mut calls = 0;
+        let result: Valid<Vec<i32>, String, ()> =
+            Valid::from_iter_limit(input, NonZeroUsize::new(2), |a| {
+                calls += 1;
+                match a % 2 {
+                    0 => Valid::succeed(a),
+                    _ => Valid::fail(a.to_string()),
+                }
+            });
+        assert_eq!(calls, 3);
+        assert_eq!(
+            result,
+            Valid::from(vec![
+                Cause::new("1".to_string()),
+                Cause::new("3".to_string()),
+                Cause::new(
+                    "stopped after 2 errors, the remaining items were not validated".to_string()
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_from_iter_limit_not_reached() {
+        let input: Vec<i32> = [1, 2, 3].to_vec();
+        let result: Valid<Vec<i32>, String, ()> =
+            Valid::from_iter_limit(input, NonZeroUsize::new(5), |a| Valid::fail(a.to_string()));
+        assert_eq!(
+            result,
+            Valid::from(vec![
+                Cause::new("1".to_string()),
+                Cause::new("2".to_string()),
+                Cause::new("3".to_string()),
+            ])
+        );
+
+        let result: Valid<Vec<i32>, String, ()> =
+            Valid::from_iter_limit([1, 2], NonZeroUsize::new(2), |a| Valid::fail(a.to_string()));
+        assert_eq!(result.to_result().unwrap_err().len(), 2);
+    }
+
+    #[test]
+    fn test_from_iter_limit_truncates_causes() {
+        let twice = |a: i32| {
+            Valid::<i32, String, ()>::fail(a.to_string()).and(Valid::fail("again".to_string()))
+        };
+        let result: Valid<Vec<i32>, String, ()> =
+            Valid::from_iter_limit([1, 2], NonZeroUsize::new(1), twice);
+        assert_eq!(
+            result,
+            Valid::from(vec![
+                Cause::new("1".to_string()),
+                Cause::new(
+                    "stopped after 1 error, 1 more error was dropped and the remaining items were not validated"
+                        .to_string()
+                ),
+            ])
+        );
+
+        let result: Valid<Vec<i32>, String, ()> =
+            Valid::from_iter_limit([1], NonZeroUsize::new(1), twice);
+        assert_eq!(
+            result,
+            Valid::from(vec![
+                Cause::new("1".to_string()),
+                Cause::new("stopped after 1 error, 1 more error was dropped".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_from_iter_no_limit() {
+        let result: Valid<Vec<i32>, String, ()> =
+            Valid::from_iter_limit([1, 2, 3], None, |a| Valid::fail(a.to_string()));
+        assert_eq!(result.to_result().unwrap_err().len(), 3);
+    }
+
     #[test]
     fn test_ok_ok_cause() {
         let option: Option<i32> = None;
@@ -594,8 +1192,8 @@ mod tests {
             .trace("C");
 
         let expected = Valid::from(vec![Cause {
-            error: 1,
             trace: vec!["C".to_string(), "B".to_string(), "A".to_string()].into(),
+            ..Cause::new(1)
         }]);
         assert_eq!(result, expected);
     }
@@ -702,6 +1300,59 @@ mod tests {
         assert_eq!(causes[0].to_string(), "[outer, inner] fail");
         assert_eq!(causes[1].to_string(), "[outer, inner] fail 2");
     }
+    #[test]
+    fn test_warnings_through_combinators() {
+        let v1 = Valid::<i32, &str, &str>::succeed(1).warn("w1").trace("a");
+        let v2 = Valid::<i32, &str, &str>::succeed(2).warn("w2");
+        let v3 = Valid::<i32, &str, &str>::fail("e3").info("i3");
+
+        let result = v1
+            .fuse(v2)
+            .to_valid()
+            .map(|(a, b)| a + b)
+            .and_then(|n| Valid::succeed(n * 2).warn("w4"))
+            .trace("root");
+
+        assert!(result.is_succeed());
+        let warnings: Vec<String> = result.warnings().iter().map(|w| w.to_string()).collect();
+        assert_eq!(warnings, vec!["[root, a] w1", "[root] w2", "[root] w4"]);
+        assert_eq!(result.clone().to_result(), Ok(6));
+
+        let failed = result.zip(v3);
+        assert!(failed.is_fail());
+        assert_eq!(failed.warnings().len(), 4);
+        assert_eq!(failed.warnings()[3].severity, Severity::Info);
+    }
+
+    #[test]
+    fn test_warnings_from_iter() {
+        let result: Valid<Vec<i32>, String, ()> = Valid::from_iter([1, 2, 3], |a| {
+            let valid = Valid::succeed(a);
+            if a % 2 == 1 {
+                valid.warn(format!("{} is odd", a))
+            } else {
+                valid
+            }
+        });
+        assert!(result.is_succeed());
+        assert_eq!(
+            result.causes(),
+            &[
+                Cause::new("1 is odd".to_string()).severity(Severity::Warning),
+                Cause::new("3 is odd".to_string()).severity(Severity::Warning)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fold_keeps_warnings() {
+        let result = Valid::<i32, &str, ()>::succeed(1)
+            .warn("w1")
+            .fold(|n| Valid::succeed(n + 1).warn("w2"), || Valid::succeed(0));
+        let warnings: Vec<&str> = result.warnings().iter().map(|w| w.error).collect();
+        assert_eq!(warnings, vec!["w1", "w2"]);
+    }
+
     #[test]
     fn test_from_result_vec_causes_ok() {
         let ok_result: Result<i32, Vec<Cause<&str, ()>>> = Ok(42);
@@ -727,4 +1378,220 @@ mod tests {
         assert_eq!(valid, expected);
         assert!(valid.is_fail());
     }
+
+    #[test]
+    fn test_from_causes_keeps_diagnostics() {
+        let warning = Cause::new("deprecated").severity(Severity::Warning);
+        let valid = Valid::<i32, &str, ()>::from(vec![Cause::new("error"), warning.clone()]);
+        assert_eq!(valid.warnings(), vec![&warning]);
+        assert_eq!(valid.to_result(), Err(vec![Cause::new("error")]));
+    }
+
+    #[test]
+    fn test_from_warnings_only() {
+        let warning = Cause::new("deprecated").severity(Severity::Warning);
+        let note = Cause::new("renamed").severity(Severity::Info);
+
+        let valid = Valid::<i32, &str, ()>::from(vec![warning.clone(), note.clone()]);
+        assert!(valid.is_fail());
+        assert_eq!(valid.warnings(), vec![&warning, &note]);
+        assert_eq!(valid.to_result(), Err(vec![]));
+
+        let valid = Valid::<i32, &str, ()>::from(Err(warning.clone()));
+        assert!(valid.is_fail());
+        assert_eq!(valid.warnings(), vec![&warning]);
+        assert_eq!(valid.to_result(), Err(vec![]));
+    }
+
+    #[test]
+    fn test_collect() {
+        let result: Valid<Vec<i32>, i32, ()> = (1..=3).map(Valid::succeed).collect();
+        assert_eq!(result, Valid::succeed(vec![1, 2, 3]));
+
+        let result: Valid<String, i32, ()> = ["a", "b"].into_iter().map(Valid::succeed).collect();
+        assert_eq!(result, Valid::succeed("ab".to_string()));
+    }
+
+    #[test]
+    fn test_collect_accumulates_causes() {
+        let result: Valid<Vec<i32>, i32, ()> = (1..=4)
+            .map(|a| match a % 2 {
+                0 => Valid::fail(a),
+                _ => Valid::succeed(a).warn(-a),
+            })
+            .collect();
+        assert_eq!(result.warnings().len(), 2);
+        assert_eq!(result.to_result(), Err(vec![Cause::new(2), Cause::new(4)]));
+    }
+
+    #[test]
+    fn test_collect_short_circuiting_container() {
+        // Collecting into an `Option` stops at the first `None`, the remaining
+        // items must be validated all the same.
+        let result: Valid<Option<Vec<i32>>, i32, ()> =
+            [Valid::fail(1), Valid::succeed(None), Valid::fail(2)]
+                .into_iter()
+                .collect();
+        assert_eq!(result, Valid::from(vec![Cause::new(1), Cause::new(2)]));
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut result = Valid::<Vec<i32>, i32, ()>::succeed(vec![]);
+        result.extend([Valid::succeed(1), Valid::succeed(2).warn(0)]);
+        assert_eq!(result.clone().to_result(), Ok(vec![1, 2]));
+
+        result.extend([Valid::fail(1), Valid::succeed(3), Valid::fail(2)]);
+        assert_eq!(result.warnings().len(), 1);
+        assert_eq!(result.to_result(), Err(vec![Cause::new(1), Cause::new(2)]));
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let sum: Valid<i32, i32, ()> = (1..=4).map(Valid::succeed).sum();
+        assert_eq!(sum, Valid::succeed(10));
+
+        let product: Valid<i32, i32, ()> = [Valid::succeed(2), Valid::fail(1), Valid::fail(2)]
+            .into_iter()
+            .product();
+        assert_eq!(product, Valid::from(vec![Cause::new(1), Cause::new(2)]));
+    }
+
+    #[test]
+    fn test_from_map() {
+        use std::collections::{BTreeMap, HashMap};
+
+        use crate::PathSegment;
+
+        let input = BTreeMap::from([("a".to_string(), 1), ("b".to_string(), 2)]);
+        let result: Valid<HashMap<String, i32>, i32, PathSegment> =
+            Valid::from_map(input.clone(), |_, v| Valid::succeed(v * 10));
+        let expected = HashMap::from([("a".to_string(), 10), ("b".to_string(), 20)]);
+        assert_eq!(result, Valid::succeed(expected));
+
+        let result: Valid<BTreeMap<String, i32>, i32, PathSegment> =
+            Valid::from_map(input, |_, v| Valid::fail(v).trace("timeout"));
+        assert_eq!(
+            result,
+            Valid::from(vec![
+                Cause::new(1)
+                    .trace("timeout".into())
+                    .trace(PathSegment::key("a")),
+                Cause::new(2)
+                    .trace("timeout".into())
+                    .trace(PathSegment::key("b")),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_from_entries() {
+        use std::collections::BTreeMap;
+
+        use crate::PathSegment;
+
+        let result: Valid<BTreeMap<i32, i32>, &str, PathSegment> = Valid::from_entries(
+            [("1", 1), ("x", 2), ("3", -3)],
+            |k| Valid::from_option(k.parse().ok(), "key is not a number"),
+            |_, v| match v {
+                v if v > 0 => Valid::succeed(v),
+                _ => Valid::fail("value is not positive"),
+            },
+        );
+        let causes: Vec<String> = result
+            .to_result()
+            .unwrap_err()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            causes,
+            vec!["[x] key is not a number", "[3] value is not positive"]
+        );
+    }
+
+    #[test]
+    fn test_fusion_map_n() {
+        let result = Valid::<i32, i32, ()>::succeed(1)
+            .fuse(Valid::succeed(2))
+            .fuse(Valid::succeed(3))
+            .fuse(Valid::succeed(4))
+            .map4(|a, b, c, d| a * 1000 + b * 100 + c * 10 + d);
+        assert_eq!(result, Valid::succeed(1234));
+
+        let result = Valid::<i32, i32, ()>::fail(1)
+            .fuse(Valid::succeed(2))
+            .fuse(Valid::<i32, i32, ()>::fail(3))
+            .map3(|a, b, c| a + b + c);
+        assert_eq!(result, Valid::from(vec![Cause::new(1), Cause::new(3)]));
+    }
+
+    #[test]
+    fn test_fusion_trace_and_then() {
+        let result = Valid::<i32, i32, String>::succeed(1)
+            .fuse(Valid::<i32, i32, String>::fail(2))
+            .trace("a")
+            .and_then(|(a, b): (i32, i32)| Valid::succeed((a, b)))
+            .fuse(Valid::<i32, i32, String>::fail(3))
+            .trace("b")
+            .map3(|a, b, c| a + b + c);
+        assert_eq!(
+            result,
+            Valid::from(vec![
+                Cause::new(2).trace("a".to_string()).trace("b".to_string()),
+                Cause::new(3).trace("b".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn test_recover() {
+        let mut calls = 0;
+        let result = Valid::<i32, i32, ()>::fail(1)
+            .warn(0)
+            .recover(|causes| causes.len() as i32 * 10)
+            .and_then(|a| {
+                calls += 1;
+                Valid::<i32, i32, ()>::fail(a + 1)
+            })
+            .zip(Valid::<i32, i32, ()>::fail(3));
+        assert_eq!(calls, 1);
+        assert!(result.is_fail());
+        assert_eq!(
+            result.to_result(),
+            Err(vec![Cause::new(1), Cause::new(21), Cause::new(3)])
+        );
+    }
+
+    #[test]
+    fn test_recover_keeps_partial_result() {
+        let mut result = Valid::<i32, i32, ()>::fail(1)
+            .recover(|_| 0)
+            .map(|a| a + 1)
+            .warn(2);
+        assert!(result.is_fail());
+        assert!(!result.is_succeed());
+        assert_eq!(
+            result.drain_warnings(),
+            vec![Cause::new(2).severity(Severity::Warning)]
+        );
+        assert_eq!(result.to_result(), Err(vec![Cause::new(1)]));
+
+        let result = Valid::<i32, i32, ()>::succeed(1).recover(|_| 0);
+        assert_eq!(result, Valid::succeed(1));
+    }
+
+    #[test]
+    fn test_recover_keeps_error_order() {
+        let result = Valid::<i32, &str, ()>::fail("a")
+            .zip(Valid::fail("b").recover(|_| 0))
+            .to_result();
+        assert_eq!(result, Err(vec![Cause::new("a"), Cause::new("b")]));
+
+        let valid = Valid::<i32, &str, ()>::fail("a").recover(|_| 0).warn("w");
+        assert_eq!(
+            valid.warnings(),
+            vec![&Cause::new("w").severity(Severity::Warning)]
+        );
+    }
 }
@@ -0,0 +1,70 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+
+use crate::{Cause, Valid, Validator};
+
+#[derive(Serialize)]
+struct JsValid<A, T> {
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<A>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    errors: Option<Vec<Cause<String, T>>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<Cause<String, T>>,
+}
+
+/// Replaces the error of a cause with its `Display` output.
+fn to_message<E: Display, T>(cause: Cause<E, T>) -> Cause<String, T> {
+    cause.transform(|error| error.to_string())
+}
+
+impl<A: Serialize, E: Display, T: Serialize> Valid<A, E, T> {
+    /// Converts the validation into a plain JavaScript object.
+    ///
+    /// A success becomes `{ ok: true, value }` and a failure becomes
+    /// `{ ok: false, errors: [{ message, path }] }`, where each message is
+    /// the error's `Display` output. Warnings and notes, if any, are listed in
+    /// a `warnings` member. Causes are serialized as by [`Cause`]'s
+    /// `Serialize` implementation, so a warning keeps its `severity` along
+    /// with any `span`, `help`, `code` and `params`. Fails only if the value
+    /// or the trace can't be represented in JavaScript.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use tailcall_valid::Valid;
+    /// use wasm_bindgen::prelude::*;
+    ///
+    /// #[wasm_bindgen]
+    /// pub fn validate_port(port: i32) -> Result<JsValue, JsValue> {
+    ///     let valid: Valid<i32, &str, &str> = if (0..=65535).contains(&port) {
+    ///         Valid::succeed(port)
+    ///     } else {
+    ///         Valid::fail_at("is not a valid port", "port")
+    ///     };
+    ///     valid.to_js_value()
+    /// }
+    /// ```
+    pub fn to_js_value(mut self) -> Result<JsValue, JsValue> {
+        let warnings = self.drain_warnings().into_iter().map(to_message).collect();
+        let valid = match self.to_result() {
+            Ok(value) => JsValid {
+                ok: true,
+                value: Some(value),
+                errors: None,
+                warnings,
+            },
+            Err(causes) => JsValid {
+                ok: false,
+                value: None,
+                errors: Some(causes.into_iter().map(to_message).collect()),
+                warnings,
+            },
+        };
+
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+        Ok(valid.serialize(&serializer)?)
+    }
+}
@@ -0,0 +1,233 @@
+use syn::meta::ParseNestedMeta;
+use syn::{Attribute, Expr, Ident, LitStr, Path, Type};
+
+/// The derive whose attributes are being parsed. Both derives share the
+/// `#[valid(...)]` attribute, and each rejects the options of the other.
+#[derive(Clone, Copy)]
+pub enum Derive {
+    ValidFrom,
+    Validate,
+}
+
+impl Derive {
+    /// Returns true if the derive reads the option named `name`.
+    fn supports(self, name: &str) -> bool {
+        match self {
+            Derive::ValidFrom => matches!(
+                name,
+                "from" | "error" | "trace" | "rename" | "skip" | "into" | "with"
+            ),
+            Derive::Validate => matches!(
+                name,
+                "error"
+                    | "trace"
+                    | "rename"
+                    | "length"
+                    | "range"
+                    | "pattern"
+                    | "email"
+                    | "url"
+                    | "nested"
+            ),
+        }
+    }
+
+    /// Fails with an error spanned on the option if the derive doesn't read
+    /// it.
+    fn check(self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        let name = match meta.path.get_ident() {
+            Some(ident) => ident.to_string(),
+            None => return Ok(()),
+        };
+        let derive = match self {
+            Derive::ValidFrom => "ValidFrom",
+            Derive::Validate => "Validate",
+        };
+        match self.supports(&name) {
+            true => Ok(()),
+            false => Err(meta.error(format!(
+                "`{}` is not supported by `#[derive({})]`",
+                name, derive
+            ))),
+        }
+    }
+}
+
+/// Options set on the struct itself through `#[valid(...)]`.
+#[derive(Default)]
+pub struct Container {
+    pub from: Option<Type>,
+    pub error: Option<Type>,
+    pub trace: Option<Type>,
+}
+
+impl Container {
+    pub fn parse(attrs: &[Attribute], derive: Derive) -> syn::Result<Self> {
+        let mut container = Container::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("valid")) {
+            attr.parse_nested_meta(|meta| {
+                derive.check(&meta)?;
+                if meta.path.is_ident("from") {
+                    container.from = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("error") {
+                    container.error = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("trace") {
+                    container.trace = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unknown container attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(container)
+    }
+
+    /// The error type of the generated `Valid`, `String` unless overridden.
+    pub fn error(&self) -> Type {
+        self.error
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(::std::string::String))
+    }
+
+    /// The trace type of the generated `Valid`, `String` unless overridden.
+    pub fn trace(&self) -> Type {
+        self.trace
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(::std::string::String))
+    }
+}
+
+/// Options set on a single field through `#[valid(...)]`.
+#[derive(Default)]
+pub struct Field {
+    pub rename: Option<LitStr>,
+    pub skip: bool,
+    pub into: bool,
+    pub with: Option<Path>,
+    pub rules: Vec<Rule>,
+}
+
+/// A check applied to a field by `#[derive(Validate)]`.
+pub enum Rule {
+    Length {
+        min: Option<Expr>,
+        max: Option<Expr>,
+    },
+    Range {
+        min: Option<Expr>,
+        max: Option<Expr>,
+    },
+    Pattern(LitStr),
+    Email,
+    Url,
+    Nested,
+}
+
+/// Parses the `(min = .., max = ..)` part of `length` and `range`.
+fn parse_bounds(meta: &ParseNestedMeta) -> syn::Result<(Option<Expr>, Option<Expr>)> {
+    let mut min = None;
+    let mut max = None;
+    meta.parse_nested_meta(|bound| {
+        if bound.path.is_ident("min") {
+            min = Some(bound.value()?.parse()?);
+        } else if bound.path.is_ident("max") {
+            max = Some(bound.value()?.parse()?);
+        } else {
+            return Err(bound.error("expected `min` or `max`"));
+        }
+        Ok(())
+    })?;
+    Ok((min, max))
+}
+
+impl Field {
+    pub fn parse(attrs: &[Attribute], derive: Derive) -> syn::Result<Self> {
+        let mut field = Field::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("valid")) {
+            attr.parse_nested_meta(|meta| {
+                derive.check(&meta)?;
+                if meta.path.is_ident("rename") {
+                    field.rename = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skip") {
+                    field.skip = true;
+                } else if meta.path.is_ident("into") {
+                    field.into = true;
+                } else if meta.path.is_ident("with") {
+                    field.with = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("length") {
+                    let (min, max) = parse_bounds(&meta)?;
+                    field.rules.push(Rule::Length { min, max });
+                } else if meta.path.is_ident("range") {
+                    let (min, max) = parse_bounds(&meta)?;
+                    field.rules.push(Rule::Range { min, max });
+                } else if meta.path.is_ident("pattern") {
+                    let pattern: LitStr = meta.value()?.parse()?;
+                    if let Err(error) = regex::Regex::new(&pattern.value()) {
+                        return Err(syn::Error::new(
+                            pattern.span(),
+                            format!("invalid pattern: {}", error),
+                        ));
+                    }
+                    field.rules.push(Rule::Pattern(pattern));
+                } else if meta.path.is_ident("email") {
+                    field.rules.push(Rule::Email);
+                } else if meta.path.is_ident("url") {
+                    field.rules.push(Rule::Url);
+                } else if meta.path.is_ident("nested") {
+                    field.rules.push(Rule::Nested);
+                } else {
+                    return Err(meta.error("unknown field attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(field)
+    }
+
+    /// The segment pushed onto the trace when this field fails.
+    pub fn trace_name(&self, ident: &Ident) -> LitStr {
+        use syn::ext::IdentExt;
+        self.rename
+            .clone()
+            .unwrap_or_else(|| LitStr::new(&ident.unraw().to_string(), ident.span()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse_quote, Attribute};
+
+    use super::{Container, Derive, Field};
+
+    #[test]
+    fn test_rejects_options_of_the_other_derive() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[valid(rename = "n", length(max = 8))])];
+        let error = Field::parse(&attrs, Derive::ValidFrom).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "`length` is not supported by `#[derive(ValidFrom)]`"
+        );
+        assert_eq!(
+            Field::parse(&attrs, Derive::Validate).unwrap().rules.len(),
+            1
+        );
+
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[valid(into)])];
+        let error = Field::parse(&attrs, Derive::Validate).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "`into` is not supported by `#[derive(Validate)]`"
+        );
+
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[valid(from = Raw, error = String)])];
+        assert!(Container::parse(&attrs, Derive::Validate).is_err());
+        assert!(Container::parse(&attrs, Derive::ValidFrom).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_invalid_pattern() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[valid(pattern = "^[a-z+$")])];
+        let error = Field::parse(&attrs, Derive::Validate).err().unwrap();
+        assert!(error.to_string().starts_with("invalid pattern: "));
+    }
+}
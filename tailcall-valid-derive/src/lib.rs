@@ -0,0 +1,110 @@
+//! Derive macros for [`tailcall-valid`](https://docs.rs/tailcall-valid).
+//!
+//! These macros are re-exported by `tailcall-valid`, the derives when its
+//! `derive` feature is enabled, and should be used through that crate.
+
+mod attr;
+mod valid;
+mod valid_from;
+mod validate;
+mod zip_all;
+
+use proc_macro::TokenStream;
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput, Ident};
+
+/// The path of `tailcall-valid` in the crate using the macros, which differs
+/// from `::tailcall_valid` when the dependency is renamed.
+fn krate() -> proc_macro2::TokenStream {
+    match crate_name("tailcall-valid") {
+        Ok(FoundCrate::Name(name)) => {
+            let name = Ident::new(&name, proc_macro2::Span::call_site());
+            quote!(::#name)
+        }
+        // Within `tailcall-valid` itself, which declares `extern crate self as
+        // tailcall_valid`, or when the manifest can't be read.
+        Ok(FoundCrate::Itself) | Err(_) => quote!(::tailcall_valid),
+    }
+}
+
+/// Derives `ValidFrom<Source>` for a struct with named fields.
+///
+/// Every field is converted from the field of the same name on the source
+/// type using its own `ValidFrom` implementation. Failures are traced with
+/// the field name and all of them are accumulated into a single `Valid`.
+///
+/// Container attributes:
+/// - `#[valid(from = Source)]` the type being converted from (required).
+/// - `#[valid(error = E)]` the error type, `String` by default.
+/// - `#[valid(trace = T)]` the trace type, `String` by default.
+///
+/// Field attributes:
+/// - `#[valid(rename = "name")]` uses `name` as the trace segment.
+/// - `#[valid(skip)]` fills the field with `Default::default()`.
+/// - `#[valid(into)]` converts the field infallibly with `Into`.
+/// - `#[valid(with = path::to::function)]` converts the field with a custom
+///   function returning `Valid<Field, E, T>`.
+///
+/// Any other option, such as the rules of `Validate`, is a compile error.
+#[proc_macro_derive(ValidFrom, attributes(valid))]
+pub fn derive_valid_from(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    valid_from::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `Validate` for a struct with named fields from declarative rules.
+///
+/// Each violated rule produces one cause traced with the field name, and all
+/// of them are accumulated into a single `Valid`. Rule messages are converted
+/// into the error type with `From<String>`.
+///
+/// Container attributes:
+/// - `#[valid(error = E)]` the error type, `String` by default.
+/// - `#[valid(trace = T)]` the trace type, `String` by default.
+///
+/// Field attributes:
+/// - `#[valid(length(min = 1, max = 64))]` bounds the length of strings and
+///   collections, either bound is optional.
+/// - `#[valid(range(min = 0, max = 100))]` bounds the value itself.
+/// - `#[valid(pattern = "^[a-z]+$")]` requires a regular expression match.
+///   An invalid regular expression is a compile error.
+/// - `#[valid(email)]` requires an email address.
+/// - `#[valid(url)]` requires an absolute url.
+/// - `#[valid(nested)]` runs the field's own `Validate` implementation.
+/// - `#[valid(rename = "name")]` uses `name` as the trace segment.
+///
+/// Any other option, such as `from` or `into` of `ValidFrom`, is a compile
+/// error.
+#[proc_macro_derive(Validate, attributes(valid))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    validate::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Writes validations in direct style, without nesting `zip` and `and_then`.
+///
+/// Consecutive `let pattern = validation?;` bindings are zipped together,
+/// `then;` and any other statement start a new group that runs with
+/// `and_then`, and the final expression is the value of the validation. See
+/// `tailcall_valid::valid!` for details.
+#[proc_macro]
+pub fn valid(input: TokenStream) -> TokenStream {
+    valid::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Combines any number of validations into a flat tuple of their values,
+/// accumulating the errors of all of them. See `tailcall_valid::zip_all!`
+/// for details.
+#[proc_macro]
+pub fn zip_all(input: TokenStream) -> TokenStream {
+    zip_all::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
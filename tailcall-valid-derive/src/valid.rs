@@ -0,0 +1,128 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse::Parser;
+use syn::{Block, Expr, Local, Pat, Stmt};
+
+/// A piece of the body of `valid!`, in source order.
+enum Step {
+    /// Consecutive `let pattern = validation?;` bindings, run together.
+    Group(Vec<(Pat, Expr)>),
+    /// Any other statement, run once the bindings before it succeeded.
+    Stmt(Box<Stmt>),
+}
+
+/// Returns the pattern and validation of a `let pattern = validation?;`
+/// binding.
+fn binding(local: &Local) -> Option<(Pat, Expr)> {
+    let init = local.init.as_ref()?;
+    match (&*init.expr, &init.diverge) {
+        (Expr::Try(expr), None) => Some((local.pat.clone(), (*expr.expr).clone())),
+        _ => None,
+    }
+}
+
+/// Returns true for the `then;` separator.
+fn is_then(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Expr(Expr::Path(expr), Some(_)) => {
+            expr.attrs.is_empty() && expr.qself.is_none() && expr.path.is_ident("then")
+        }
+        _ => false,
+    }
+}
+
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let krate = crate::krate();
+    let mut stmts = Block::parse_within.parse2(input)?;
+
+    // The value of the validation: a trailing `validation?` is returned as is,
+    // any other trailing expression is the value of a success.
+    let mut tail = match stmts.pop() {
+        Some(Stmt::Expr(Expr::Try(expr), None)) => {
+            let expr = &expr.expr;
+            quote!(#krate::Validator::to_valid(#expr))
+        }
+        Some(Stmt::Expr(expr, None)) => quote!(#krate::Valid::succeed({ #expr })),
+        Some(Stmt::Macro(expr)) if expr.semi_token.is_none() => {
+            quote!(#krate::Valid::succeed({ #expr }))
+        }
+        Some(stmt) => {
+            stmts.push(stmt);
+            quote!(#krate::Valid::succeed(()))
+        }
+        None => quote!(#krate::Valid::succeed(())),
+    };
+
+    let mut steps: Vec<Step> = Vec::new();
+    for stmt in stmts {
+        let bound = match &stmt {
+            Stmt::Local(local) => binding(local),
+            _ => None,
+        };
+        match (bound, steps.last_mut()) {
+            (Some(bound), Some(Step::Group(group))) => group.push(bound),
+            (Some(bound), _) => steps.push(Step::Group(vec![bound])),
+            (None, _) if is_then(&stmt) => steps.push(Step::Group(Vec::new())),
+            (None, _) => steps.push(Step::Stmt(Box::new(stmt))),
+        }
+    }
+
+    // Nests the steps from the last one, so that every step runs inside the
+    // continuation of the ones before it.
+    for step in steps.into_iter().rev() {
+        tail = match step {
+            Step::Stmt(stmt) => quote!({ #stmt #tail }),
+            Step::Group(group) => {
+                // The closure binds every value to a name of its own, and the
+                // patterns are matched by `let` statements in its body, so
+                // that typed patterns such as `a: u64` work as in a plain
+                // `let`.
+                let vars: Vec<_> = (0..group.len())
+                    .map(|i| {
+                        format_ident!("__tailcall_valid_value{}", i, span = Span::mixed_site())
+                    })
+                    .collect();
+                let mut pairs = group.iter().zip(&vars);
+                let Some(((_, expr), var)) = pairs.next() else {
+                    continue;
+                };
+                let mut valid = quote!(#krate::Validator::to_valid(#expr));
+                let mut pattern = quote!(#var);
+                for ((_, expr), var) in pairs {
+                    valid = quote! {
+                        #krate::Validator::zip(
+                            #valid,
+                            #krate::Validator::to_valid(#expr),
+                        )
+                    };
+                    pattern = quote!((#pattern, #var));
+                }
+                let pats = group.iter().map(|(pat, _)| pat);
+                quote! {
+                    #krate::Validator::and_then(#valid, |#pattern| {
+                        #(let #pats = #vars;)*
+                        #tail
+                    })
+                }
+            }
+        };
+    }
+    Ok(tail)
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::{format_ident, quote};
+
+    use super::expand;
+
+    #[test]
+    fn test_long_body() {
+        let bindings = (0..1000u64).map(|i| {
+            let name = format_ident!("a{}", i);
+            quote!(let #name: u64 = check(#i)?;)
+        });
+        let output = expand(quote!(#(#bindings)* then; a0 + a999)).unwrap();
+        assert!(output.to_string().contains("let a999 : u64"));
+    }
+}
@@ -0,0 +1,114 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields};
+
+use crate::attr::{self, Derive};
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let krate = crate::krate();
+    let container = attr::Container::parse(&input.attrs, Derive::ValidFrom)?;
+    let source = container.from.clone().ok_or_else(|| {
+        syn::Error::new_spanned(
+            &input.ident,
+            "missing source type, add `#[valid(from = SourceType)]`",
+        )
+    })?;
+    let error = container.error();
+    let trace = container.trace();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "ValidFrom can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "ValidFrom can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut bindings = Vec::new();
+    let mut idents = Vec::new();
+    let mut vars = Vec::new();
+    for field in fields {
+        let options = attr::Field::parse(&field.attrs, Derive::ValidFrom)?;
+        let ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let var = format_ident!("__tailcall_valid_field_{}", ident);
+        let name = options.trace_name(ident);
+
+        let conflicting = [options.skip, options.into, options.with.is_some()]
+            .iter()
+            .filter(|set| **set)
+            .count();
+        if conflicting > 1 {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "`skip`, `into` and `with` are mutually exclusive",
+            ));
+        }
+
+        let value = if options.skip {
+            quote!(#krate::Valid::succeed(
+                ::core::default::Default::default()
+            ))
+        } else if options.into {
+            quote!(#krate::Valid::succeed(::core::convert::Into::into(
+                __tailcall_valid_source.#ident
+            )))
+        } else if let Some(with) = &options.with {
+            quote!(#krate::Validator::trace(#with(__tailcall_valid_source.#ident), #name))
+        } else {
+            quote!(#krate::Validator::trace(
+                #krate::ValidFrom::valid_from(__tailcall_valid_source.#ident),
+                #name
+            ))
+        };
+
+        bindings.push(quote! {
+            let #var: #krate::Valid<#ty, #error, #trace> = #value;
+        });
+        idents.push(ident);
+        vars.push(var);
+    }
+
+    // Fold every field into a single nested `zip` so all causes are collected.
+    let combined = match vars.split_first() {
+        None => quote!(#krate::Valid::succeed(Self {})),
+        Some((first, rest)) => {
+            let mut valid = quote!(#first);
+            let mut pattern = quote!(#first);
+            for var in rest {
+                valid = quote!(#krate::Validator::zip(#valid, #var));
+                pattern = quote!((#pattern, #var));
+            }
+            quote! {
+                #krate::Validator::map(#valid, |#pattern| Self {
+                    #(#idents: #vars),*
+                })
+            }
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #krate::ValidFrom<#source> for #name #ty_generics #where_clause {
+            type Error = #error;
+            type Trace = #trace;
+
+            #[allow(unused_variables)]
+            fn valid_from(__tailcall_valid_source: #source) -> #krate::Valid<Self, #error, #trace> {
+                #(#bindings)*
+                #combined
+            }
+        }
+    })
+}
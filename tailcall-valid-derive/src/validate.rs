@@ -0,0 +1,90 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Expr, Fields};
+
+use crate::attr::{self, Derive, Rule};
+
+fn bound(expr: &Option<Expr>) -> TokenStream {
+    match expr {
+        Some(expr) => quote!(::core::option::Option::Some(#expr)),
+        None => quote!(::core::option::Option::None),
+    }
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let krate = crate::krate();
+    let container = attr::Container::parse(&input.attrs, Derive::Validate)?;
+    let error = container.error();
+    let trace = container.trace();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "Validate can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Validate can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut checks = Vec::new();
+    for field in fields {
+        let options = attr::Field::parse(&field.attrs, Derive::Validate)?;
+        let ident = field.ident.as_ref().expect("named field");
+        let name = options.trace_name(ident);
+
+        for rule in &options.rules {
+            let check = match rule {
+                Rule::Length { min, max } => {
+                    let (min, max) = (bound(min), bound(max));
+                    quote!(#krate::rule::length(&self.#ident, #min, #max))
+                }
+                Rule::Range { min, max } => {
+                    let (min, max) = (bound(min), bound(max));
+                    quote!(#krate::rule::range(&self.#ident, #min, #max))
+                }
+                Rule::Pattern(pattern) => quote! {{
+                    static PATTERN: ::std::sync::OnceLock<#krate::rule::Regex> =
+                        ::std::sync::OnceLock::new();
+                    let regex = PATTERN.get_or_init(|| {
+                        #krate::rule::Regex::new(#pattern).expect("pattern is checked by the derive")
+                    });
+                    #krate::rule::pattern(&self.#ident, regex)
+                }},
+                Rule::Email => quote!(#krate::rule::email(&self.#ident)),
+                Rule::Url => quote!(#krate::rule::url(&self.#ident)),
+                Rule::Nested => quote!(#krate::Validate::validate(&self.#ident)),
+            };
+            checks.push(quote! {
+                let __valid = #krate::Validator::and(
+                    __valid,
+                    #krate::Validator::trace(#check, #name),
+                );
+            });
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #krate::Validate for #name #ty_generics #where_clause {
+            type Error = #error;
+            type Trace = #trace;
+
+            fn validate(&self) -> #krate::Valid<(), #error, #trace> {
+                let __valid: #krate::Valid<(), #error, #trace> =
+                    #krate::Valid::succeed(());
+                #(#checks)*
+                __valid
+            }
+        }
+    })
+}
@@ -0,0 +1,43 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{Expr, Token};
+
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let krate = crate::krate();
+    let exprs = Punctuated::<Expr, Token![,]>::parse_terminated.parse2(input)?;
+    let names: Vec<_> = (0..exprs.len())
+        .map(|i| format_ident!("__value{}", i, span = Span::mixed_site()))
+        .collect();
+
+    // Zips the validations from the first one, so that their causes keep the
+    // order of the arguments.
+    let mut pairs = exprs.iter().zip(&names);
+    let Some((first, name)) = pairs.next() else {
+        return Ok(quote!(#krate::Valid::succeed(())));
+    };
+    let mut valid = quote!(#krate::Validator::to_valid(#first));
+    let mut pattern = quote!(#name);
+    for (expr, name) in pairs {
+        valid = quote! {
+            #krate::Validator::zip(#valid, #krate::Validator::to_valid(#expr))
+        };
+        pattern = quote!((#pattern, #name));
+    }
+    Ok(quote!(#krate::Validator::map(#valid, |#pattern| (#(#names,)*))))
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::expand;
+
+    #[test]
+    fn test_many_arguments() {
+        let args = (0..1000u64).map(|i| quote!(check(#i)));
+        let output = expand(quote!(#(#args),*)).unwrap().to_string();
+        assert!(output.contains("__value999"));
+    }
+}
@@ -1,3 +1,8 @@
+use gh_workflow::generate::Generate;
+use gh_workflow::toolchain::{Arch, System, Toolchain, Vendor};
+use gh_workflow::{
+    Cargo, Event, Job, Level, Permissions, PullRequest, PullRequestType, Push, RustFlags, Step,
+};
 use gh_workflow_tailcall::*;
 
 #[test]
@@ -8,3 +13,41 @@ fn generate_ci_workflow() {
 
     workflow.generate().unwrap();
 }
+
+#[test]
+fn generate_wasm_workflow() {
+    let event = Event::default()
+        .push(Push::default().add_branch("main"))
+        .pull_request(
+            PullRequest::default()
+                .add_type(PullRequestType::Opened)
+                .add_type(PullRequestType::Synchronize)
+                .add_type(PullRequestType::Reopened)
+                .add_branch("main"),
+        );
+    let job = Job::new("Wasm")
+        .permissions(Permissions::default().contents(Level::Read))
+        .add_step(Step::checkout())
+        .add_step(Toolchain::default().add_stable().target(
+            Arch::Wasm32,
+            Vendor::Unknown,
+            System::Unknown,
+            None,
+        ))
+        .add_step(
+            Cargo::new("check")
+                .args("--target wasm32-unknown-unknown --features wasm --tests")
+                .name("Cargo Check"),
+        )
+        .add_step(Step::uses("jetli", "wasm-pack-action", "v0.4.0").name("Setup wasm-pack"))
+        .add_step(
+            Step::run("wasm-pack test --node -- --features wasm --test wasm")
+                .name("Wasm Pack Test"),
+        );
+    let workflow = gh_workflow::Workflow::new("Wasm")
+        .add_env(RustFlags::deny("warnings"))
+        .on(event)
+        .add_job("wasm", job);
+
+    Generate::new(workflow).name("wasm.yml").generate().unwrap();
+}
@@ -0,0 +1,228 @@
+#![cfg(feature = "derive")]
+
+use pretty_assertions::assert_eq;
+use tailcall_valid::{Cause, Valid, ValidFrom, ValidInto, Validate, Validator};
+
+struct RawPort(i64);
+
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+impl ValidFrom<RawPort> for Port {
+    type Error = String;
+    type Trace = String;
+
+    fn valid_from(raw: RawPort) -> Valid<Self, String, String> {
+        match u16::try_from(raw.0) {
+            Ok(port) => Valid::succeed(Port(port)),
+            Err(_) => Valid::fail(format!("{} is not a valid port", raw.0)),
+        }
+    }
+}
+
+struct RawServer {
+    host: String,
+    port: RawPort,
+    admin_port: RawPort,
+    timeout: i64,
+}
+
+fn positive(value: i64) -> Valid<u64, String, String> {
+    if value > 0 {
+        Valid::succeed(value as u64)
+    } else {
+        Valid::fail("must be positive".to_string())
+    }
+}
+
+#[derive(Debug, PartialEq, ValidFrom)]
+#[valid(from = RawServer)]
+struct Server {
+    #[valid(into)]
+    host: String,
+    port: Port,
+    #[valid(rename = "adminPort")]
+    admin_port: Port,
+    #[valid(with = positive)]
+    timeout: u64,
+    #[valid(skip)]
+    workers: Vec<String>,
+}
+
+fn raw_server(port: i64, admin_port: i64, timeout: i64) -> RawServer {
+    RawServer {
+        host: "localhost".to_string(),
+        port: RawPort(port),
+        admin_port: RawPort(admin_port),
+        timeout,
+    }
+}
+
+#[test]
+fn test_derive_succeed() {
+    let actual: Valid<Server, String, String> = raw_server(80, 8080, 30).valid_into();
+    let expected = Server {
+        host: "localhost".to_string(),
+        port: Port(80),
+        admin_port: Port(8080),
+        timeout: 30,
+        workers: vec![],
+    };
+    assert_eq!(actual, Valid::succeed(expected));
+}
+
+#[test]
+fn test_derive_accumulates_errors() {
+    let actual = Server::valid_from(raw_server(-1, 70000, 0));
+    let expected = Valid::from(vec![
+        Cause::new("-1 is not a valid port".to_string()).trace("port".to_string()),
+        Cause::new("70000 is not a valid port".to_string()).trace("adminPort".to_string()),
+        Cause::new("must be positive".to_string()).trace("timeout".to_string()),
+    ]);
+    assert_eq!(actual, expected);
+}
+
+struct RawCluster {
+    ports: Vec<RawPort>,
+    fallback: Option<RawPort>,
+}
+
+#[derive(Debug, PartialEq, ValidFrom)]
+#[valid(from = RawCluster)]
+struct Cluster {
+    ports: Vec<Port>,
+    fallback: Option<Port>,
+}
+
+#[test]
+fn test_derive_containers() {
+    let actual = Cluster::valid_from(RawCluster {
+        ports: vec![RawPort(80), RawPort(-1)],
+        fallback: Some(RawPort(70000)),
+    });
+    let expected = Valid::from(vec![
+        Cause::new("-1 is not a valid port".to_string())
+            .trace("1".to_string())
+            .trace("ports".to_string()),
+        Cause::new("70000 is not a valid port".to_string()).trace("fallback".to_string()),
+    ]);
+    assert_eq!(actual, expected);
+
+    let actual = Cluster::valid_from(RawCluster {
+        ports: vec![RawPort(80)],
+        fallback: None,
+    });
+    let expected = Cluster {
+        ports: vec![Port(80)],
+        fallback: None,
+    };
+    assert_eq!(actual, Valid::succeed(expected));
+}
+
+struct RawEmpty;
+
+#[derive(Debug, PartialEq, ValidFrom)]
+#[valid(from = RawEmpty, error = &'static str, trace = &'static str)]
+struct Empty {}
+
+#[test]
+fn test_derive_no_fields() {
+    let actual = Empty::valid_from(RawEmpty);
+    assert_eq!(actual, Valid::succeed(Empty {}));
+    assert!(actual.is_succeed());
+}
+
+struct RawMessage {
+    source: i64,
+    other: i64,
+}
+
+#[derive(Debug, PartialEq, ValidFrom)]
+#[valid(from = RawMessage)]
+struct Message {
+    #[valid(with = positive)]
+    source: u64,
+    #[valid(with = positive)]
+    other: u64,
+}
+
+#[test]
+fn test_derive_field_named_source() {
+    let actual = Message::valid_from(RawMessage {
+        source: 1,
+        other: 2,
+    });
+    assert_eq!(
+        actual,
+        Valid::succeed(Message {
+            source: 1,
+            other: 2
+        })
+    );
+}
+
+#[derive(Validate)]
+struct Address {
+    #[valid(length(min = 1))]
+    city: String,
+}
+
+#[derive(Validate)]
+struct User {
+    #[valid(length(min = 1, max = 8), pattern = "^[a-z]+$")]
+    name: String,
+    #[valid(range(min = 18, max = 150))]
+    age: u8,
+    #[valid(email)]
+    email: String,
+    #[valid(url, rename = "homePage")]
+    home_page: String,
+    #[valid(nested)]
+    address: Address,
+}
+
+fn user() -> User {
+    User {
+        name: "alice".to_string(),
+        age: 30,
+        email: "alice@example.com".to_string(),
+        home_page: "https://example.com".to_string(),
+        address: Address {
+            city: "Paris".to_string(),
+        },
+    }
+}
+
+#[test]
+fn test_validate_succeed() {
+    assert_eq!(user().validate(), Valid::succeed(()));
+}
+
+#[test]
+fn test_validate_accumulates_errors() {
+    let user = User {
+        name: "Alice Smith".to_string(),
+        age: 12,
+        email: "alice".to_string(),
+        home_page: "example.com".to_string(),
+        address: Address {
+            city: String::new(),
+        },
+    };
+    let actual: Vec<String> = user
+        .validate()
+        .to_result()
+        .unwrap_err()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    let expected = vec![
+        "[name] length must be at most 8, found 11",
+        "[name] does not match pattern `^[a-z]+$`",
+        "[age] must be at least 18, found 12",
+        "[email] is not a valid email address",
+        "[homePage] is not a valid url",
+        "[address, city] length must be at least 1, found 0",
+    ];
+    assert_eq!(actual, expected);
+}
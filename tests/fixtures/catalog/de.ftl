@@ -0,0 +1,2 @@
+string.too_long = darf höchstens { $max } Zeichen lang sein, ist aber { $actual }
+field.required = Pflichtfeld
@@ -0,0 +1,7 @@
+# Messages for validation errors.
+
+string.too_long = must be at most { $max } characters, got { $actual }
+
+field.required =
+    this field is required
+    add it to the configuration
@@ -0,0 +1,42 @@
+#![cfg(feature = "testing")]
+
+use serde::Deserialize;
+use tailcall_valid::testing::Snapshots;
+use tailcall_valid::{PathSegment, Valid, Validator};
+
+#[derive(Deserialize)]
+struct Config {
+    host: String,
+    port: u16,
+    workers: Vec<u8>,
+}
+
+fn check(ok: bool, message: &str, field: &str) -> Valid<(), String, PathSegment> {
+    match ok {
+        true => Valid::succeed(()),
+        false => Valid::fail(message.to_string()).trace(PathSegment::from(field)),
+    }
+}
+
+fn config(input: &str) -> Valid<Config, String, PathSegment> {
+    Valid::from_json_str(input).and_then(|config: Config| {
+        check(!config.host.is_empty(), "must not be empty", "host")
+            .and(check(config.port != 0, "port 0 is reserved", "port"))
+            .and(check(
+                !config.workers.is_empty(),
+                "at least one worker is required",
+                "workers",
+            ))
+            .map_to(config)
+    })
+}
+
+#[test]
+fn config_snapshots() {
+    Snapshots::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/snapshots"
+    ))
+    .extension("json")
+    .run(config);
+}
@@ -0,0 +1,54 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use serde_json::json;
+use tailcall_valid::{PathSegment, Valid, Validator};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn to_json(valid: Valid<Vec<u32>, String, PathSegment>) -> serde_json::Value {
+    let value = valid.to_js_value().unwrap();
+    serde_wasm_bindgen::from_value(value).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn test_succeed() {
+    let valid = Valid::succeed(vec![1, 2]);
+    assert_eq!(to_json(valid), json!({ "ok": true, "value": [1, 2] }));
+}
+
+#[wasm_bindgen_test]
+fn test_warnings() {
+    let valid = Valid::succeed(vec![1])
+        .warn("deprecated".to_string())
+        .trace("ports");
+    assert_eq!(
+        to_json(valid),
+        json!({
+            "ok": true,
+            "value": [1],
+            "warnings": [{ "message": "deprecated", "path": ["ports"], "severity": "warning" }]
+        })
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_fail() {
+    let valid = Valid::from_iter_indexed(vec![1, -2, -3], |n: i32| {
+        if n > 0 {
+            Valid::succeed(n as u32)
+        } else {
+            Valid::fail(format!("{} is negative", n))
+        }
+    })
+    .trace("ports");
+
+    assert_eq!(
+        to_json(valid),
+        json!({
+            "ok": false,
+            "errors": [
+                { "message": "-2 is negative", "path": ["ports", 1] },
+                { "message": "-3 is negative", "path": ["ports", 2] }
+            ]
+        })
+    );
+}